@@ -1,125 +1,363 @@
+use std::io::Error;
+use std::time::Duration;
+
 use crate::data;
 use crate::errors;
+use crate::models::command::Parsed;
+use crate::models::filter::Filter;
+use crate::models::order::Order;
+use crate::models::selector::{Selection, Selector};
+use crate::models::todo::{Destination, Todo, TodoUpdate};
+use crate::utils;
+use crate::utils::charts::parse_chart;
+use crate::utils::dates::{parse_date, parse_month, parse_recurrence, parse_since};
+use crate::utils::todos::{parse_estimate, parse_group, parse_priority, parse_sort};
 use crate::view;
 
 // * Command line argument handler
 pub fn handler(args: Vec<String>) {
-    match args.len() {
-        // * no arguments - displays the todo list (e.g. `todo`)
-        1 => {
-            if let Err(e) = view::todos::all() {
-                errors::general(e)
-            }
-        }
+    // Parse the arguments (without the program name) against the command definitions
+    let parsed = match utils::args::parse(&args[1..]) {
+        Ok(parsed) => parsed,
+        Err(e) => return errors::general(e),
+    };
+
+    if let Err(e) = run(&parsed) {
+        errors::general(e)
+    }
+}
+
+// 🔒 PRIVATE ---------------------------------
 
-        // * help - displays the usage (e.g. `todo help`)
-        2 if args[1] == "help" || args[1] == "h" || args[1] == "-h" || args[1] == "--help" => {
+// ? Runs the parsed command
+fn run(parsed: &Parsed) -> Result<(), Error> {
+    data::theme::init();
+
+    // * --help - displays the usage of the command (e.g. `todo add --help`)
+    if parsed.help {
+        if parsed.command.name.is_empty() {
             view::help::usage();
+        } else {
+            view::help::command_usage(parsed.command);
         }
+        return Ok(());
+    }
 
-        // * clear - clears the todo list (e.g. `todo clear`)
-        2 if args[1] == "clear" || args[1] == "c" => {
-            if let Err(e) = data::todos::clear() {
-                errors::general(e)
-            }
-        }
+    // * --truncate - cuts long todos to the terminal width instead of wrapping them (e.g. `todo ls --truncate`)
+    utils::terminal::set_truncate(parsed.has_flag("truncate"));
 
-        // * edit - edits todos interactively (e.g. `todo edit`)
-        2 if args[1] == "edit" || args[1] == "e" => {
-            if let Err(e) = data::todos::edit(None) {
-                errors::general(e)
-            }
+    match parsed.command.name {
+        // * no arguments - displays the todo list (e.g. `todo`)
+        "" => view::todos::all(),
+
+        // * est <INDEX> <ESTIMATE> - sets the estimate of a todo (e.g. `todo est 2 1h30m` or `todo est 2 3pt`)
+        "est" => {
+            let update = TodoUpdate {
+                estimate: Some(
+                    optional(&parsed.args[1])
+                        .then(|| parse_estimate(&parsed.args[1]))
+                        .transpose()?,
+                ),
+                ..TodoUpdate::default()
+            };
+            data::todos::set(&Selection::path(&parsed.path(0)), &update)
         }
 
-        // * edit <INDEX> - edits todo at specific index (e.g. `todo edit 2`)
-        3 if args[1] == "edit" || args[1] == "e" => match args[2].parse::<usize>() {
-            Ok(index) => {
-                if let Err(e) = data::todos::edit(Some(index)) {
-                    errors::general(e)
-                }
-            }
-            Err(_) => errors::invalid_number(&args[2]),
+        // * plan --capacity <ESTIMATE> - proposes the todos that fit (e.g. `todo plan --capacity 6h`)
+        "plan" => match parsed.flag_value("capacity") {
+            Some(capacity) => view::todos::plan(parse_estimate(capacity)?),
+            None => Err(errors::missing_flag(parsed.command, "capacity")),
         },
 
-        // * add <TEXT> - adds a new todo (e.g. `todo add "Buy milk"`)
-        3 if args[1] == "add" || args[1] == "a" => {
-            if let Err(e) = data::todos::add(&args[2], None) {
-                errors::general(e)
+        // * note <INDEX> - writes the notes of a todo (e.g. `todo note 2` or `cat steps.md | todo note 2`)
+        "note" => data::todos::note(&parsed.path(0)),
+
+        // * show <INDEX> - shows a todo with its notes (e.g. `todo show 2`)
+        "show" => data::todos::show(&parsed.path(0)),
+
+        // * block <INDEX> --on <INDEX> - makes a todo wait on others (e.g. `todo block 3 --on 1`)
+        "block" => {
+            let blockers = blockers(parsed)?;
+            if blockers.is_empty() {
+                return Err(errors::missing_flag(parsed.command, "on"));
             }
+            data::todos::block(&parsed.path(0), &blockers)
         }
 
-        // * add <TEXT> <INDEX> - adds todo at index
-        // ? (e.g. `todo add "Buy milk" 2`)
-        4 if args[1] == "add" || args[1] == "a" => match args[3].parse::<usize>() {
-            Ok(index) => {
-                if let Err(e) = data::todos::add(&args[2], Some(index)) {
-                    errors::general(e)
-                }
-            }
-            Err(_) => errors::invalid_number(&args[3]),
-        },
+        // * unblock <INDEX> [--on <INDEX>] - stops a todo from waiting (e.g. `todo unblock 3`)
+        "unblock" => data::todos::unblock(&parsed.path(0), &blockers(parsed)?),
 
-        // * rm - removes first todo (e.g. `todo rm`)
-        2 if args[1] == "rm" || args[1] == "remove" || args[1] == "r" => {
-            // If no index is provided, remove the first todo
-            if let Err(e) = data::todos::remove(1) {
-                errors::general(e)
-            }
+        // * mv <FROM> <TO> - moves a todo to another position (e.g. `todo mv 3 1`)
+        "mv" => data::todos::move_todo(
+            parsed.number(0).unwrap_or_default(),
+            Destination::Index(parsed.number(1).unwrap_or_default()),
+        ),
+
+        // * up/down/top/bottom <INDEX> - moves a todo (e.g. `todo up 3` or `todo top 3`)
+        "up" => data::todos::move_todo(parsed.number(0).unwrap_or_default(), Destination::Up),
+        "down" => data::todos::move_todo(parsed.number(0).unwrap_or_default(), Destination::Down),
+        "top" => data::todos::move_todo(parsed.number(0).unwrap_or_default(), Destination::Top),
+        "bottom" => {
+            data::todos::move_todo(parsed.number(0).unwrap_or_default(), Destination::Bottom)
         }
 
-        // * rm <INDEX> - removes todo at index (e.g. `todo rm 2`)
-        3 if args[1] == "rm" || args[1] == "r" => match args[2].parse::<usize>() {
-            Ok(index) => {
-                if let Err(e) = data::todos::remove(index) {
-                    errors::general(e)
-                }
-            }
-            Err(_) => errors::invalid_number(&args[2]),
-        },
+        // * start <INDEX> - starts the timer of a todo (e.g. `todo start 2`)
+        "start" => data::todos::start(&parsed.path(0)),
 
-        // * done - toggles first todo (e.g. `todo done`)
-        2 if args[1] == "done" || args[1] == "d" => {
-            if let Err(e) = data::todos::toggle(1) {
-                errors::general(e)
+        // * stop - stops the running timer (e.g. `todo stop`)
+        "stop" => data::todos::stop(),
+
+        // * focus <INDEX> - runs a pomodoro countdown for a todo (e.g. `todo focus 2 --minutes 50`)
+        "focus" => data::focus::run(
+            &parsed.path(0),
+            minutes(parsed, "minutes", 25)?,
+            minutes(parsed, "break", 5)?,
+        ),
+
+        // * report --time - totals the time worked per todo and per tag
+        // ? (e.g. `todo report --time --from 1w` or `todo report --time --from 2026-10-01 --to 2026-10-31`)
+        "report" => {
+            if !parsed.has_flag("time") {
+                return Err(errors::missing_flag(parsed.command, "time"));
             }
+            let from = parsed.flag_value("from").map(parse_since).transpose()?;
+            let to = parsed.flag_value("to").map(parse_date).transpose()?;
+            view::report::time(from, to)
         }
 
-        // * done <INDEX> - toggles todo at index (e.g. `todo done 2`)
-        3 if args[1] == "done" || args[1] == "d" => match args[2].parse::<usize>() {
-            Ok(index) => {
-                if let Err(e) = data::todos::toggle(index) {
-                    errors::general(e)
-                }
+        // * agenda - shows the open todos grouped by when they are due (e.g. `todo agenda`)
+        "agenda" => view::todos::agenda(),
+
+        // * cal [MONTH] - shows a month grid with the todos due on each day (e.g. `todo cal` or `todo cal nov`)
+        "cal" => view::calendar::show(match parsed.arg(0) {
+            Some(month) => parse_month(month)?,
+            None => utils::dates::this_month(),
+        }),
+
+        // * board - shows the todos as cards in the board columns (e.g. `todo board`)
+        "board" => view::board::show(),
+
+        // * stats - shows statistics about the todo list (e.g. `todo stats` or `todo stats --json`)
+        "stats" => view::stats::show(parsed.has_flag("json")),
+
+        // * chart <CHART> - draws a chart of the completion history (e.g. `todo chart burndown --since 2w`)
+        "chart" => view::charts::show(
+            parse_chart(&parsed.args[0])?,
+            parse_since(parsed.flag_value("since").unwrap_or("2w"))?,
+        ),
+
+        // * help [COMMAND] - displays the usage (e.g. `todo help` or `todo help add`)
+        "help" => {
+            match parsed.arg(0) {
+                Some(name) => view::help::command_usage(utils::args::find_command(name)?),
+                None => view::help::usage(),
             }
-            Err(_) => errors::invalid_number(&args[2]),
-        },
+            Ok(())
+        }
+
+        // * clear - clears the todo list (e.g. `todo clear`)
+        "clear" => data::todos::clear(),
+
+        // * edit [INDEX] - edits todos interactively (e.g. `todo edit` or `todo edit 2`)
+        "edit" => data::todos::edit(parsed.number(0)),
+
+        // * ls [TEXT] - lists the todos matching the filters, sorted and grouped
+        // ? (e.g. `todo ls milk --open --tag shopping --sort due --group-by tag`)
+        "ls" => view::todos::list(&filter(parsed)?, &order(parsed)?),
+
+        // * ui - opens the persistent interactive mode (e.g. `todo ui`)
+        "ui" => data::editor::run(None, true),
+
+        // * add <TEXT> [INDEX] - adds a new todo, optionally at index or under another todo
+        // ? (e.g. `todo add "Buy milk" 2 --due tomorrow` or `todo add "Step" --under 3`)
+        "add" => data::todos::add(new_todo(parsed)?, parsed.number(1), under(parsed)?),
+
+        // * rm [SELECTORS] - removes the selected todos, or the first todo
+        // ? (e.g. `todo rm 2`, `todo rm 2..4` or `todo rm --match "standup"`)
+        "rm" => data::todos::remove(&selection(parsed)?),
+
+        // * done [SELECTORS] - toggles the selected todos, or the first todo
+        // ? (e.g. `todo done 2`, `todo done 1 3 5-8`, `todo done /milk/` or `todo done 3 --cascade`)
+        "done" => data::todos::toggle(&selection(parsed)?, parsed.has_flag("cascade")),
 
         // * replace <TEXT> <INDEX> - replaces a todo at index
         // ? e.g. `todo replace "Buy milk" 2`
-        4 if args[1] == "replace" || args[1] == "rp" => match args[3].parse::<usize>() {
-            Ok(index) => {
-                if let Err(e) = data::todos::replace(index, &args[2]) {
-                    errors::general(e)
-                }
-            }
-            Err(_) => errors::invalid_number(&args[3]),
-        },
+        "replace" => data::todos::replace(&parsed.path(1), &parsed.args[0]),
 
         // * insert <TEXT> <INDEX> - inserts a todo at index
         // ? e.g. `todo insert "Buy milk" 2`
-        4 if args[1] == "insert" || args[1] == "i" => match args[3].parse::<usize>() {
-            Ok(index) => {
-                if let Err(e) = data::todos::add(&args[2], Some(index)) {
-                    errors::general(e)
-                }
-            }
-            Err(_) => errors::invalid_number(&args[3]),
-        },
+        "insert" => data::todos::add(new_todo(parsed)?, parsed.number(1), under(parsed)?),
 
-        // * - any other command - displays the usage (e.g. `todo unknown`)
-        _ => {
-            eprintln!("Invalid command");
-            view::help::usage();
+        // * set <INDEXES> - sets the metadata of the selected todos
+        // ? e.g. `todo set 2 3 --tag work --due fri --priority high`
+        "set" => {
+            let update = update(parsed)?;
+            if update.is_empty() {
+                return Err(errors::nothing_to_update(parsed.command));
+            }
+            data::todos::set(&selection(parsed)?, &update)
         }
+
+        // ? Every defined command must be handled above
+        name => unreachable!("Unhandled command: {}", name),
+    }
+}
+
+// ? Builds the selection of todos passed to the command, or the first todo if none
+fn selection(parsed: &Parsed) -> Result<Selection, Error> {
+    let mut selectors = Vec::new();
+
+    for arg in &parsed.args {
+        selectors.extend(utils::selectors::parse_arg(arg)?);
+    }
+
+    if let Some(text) = parsed.flag_value("match") {
+        selectors.push(Selector::Text(text.to_string()));
     }
+
+    if let Some(text) = parsed.flag_value("fuzzy") {
+        selectors.push(Selector::Fuzzy(text.to_string()));
+    }
+
+    if selectors.is_empty() {
+        return Ok(Selection::index(1));
+    }
+
+    Ok(Selection {
+        selectors,
+        all: parsed.has_flag("all"),
+    })
+}
+
+// ? Builds a new todo from the text argument and the metadata flags
+fn new_todo(parsed: &Parsed) -> Result<Todo, Error> {
+    let mut todo = Todo::new(&parsed.args[0]);
+    todo.apply(&update(parsed)?);
+    Ok(todo)
+}
+
+// ? Reads the parent path of a new subtask (e.g. `--under 3.2`)
+fn under(parsed: &Parsed) -> Result<Option<Vec<usize>>, Error> {
+    parsed
+        .flag_value("under")
+        .map(utils::todos::parse_path)
+        .transpose()
+}
+
+// ? Reads a length in minutes from a flag, or the default one (e.g. `--minutes 50`)
+// ? Lengths past a day are refused, a pomodoro or a break never lasts that long
+fn minutes(parsed: &Parsed, flag: &str, default: u64) -> Result<Duration, Error> {
+    const MAX_MINUTES: u64 = 24 * 60;
+
+    let minutes = match parsed.flag_value(flag) {
+        Some(value) => value
+            .parse::<u64>()
+            .ok()
+            .filter(|minutes| (1..=MAX_MINUTES).contains(minutes))
+            .ok_or_else(|| errors::invalid_number(value))?,
+        None => default,
+    };
+
+    let seconds = minutes
+        .checked_mul(60)
+        .ok_or_else(|| errors::invalid_number(&minutes.to_string()))?;
+    Ok(Duration::from_secs(seconds))
+}
+
+// ? Reads the todos passed with `--on` (e.g. `todo block 3 --on 1 --on 2.1`)
+fn blockers(parsed: &Parsed) -> Result<Vec<Vec<usize>>, Error> {
+    parsed
+        .flag_values("on")
+        .into_iter()
+        .map(utils::todos::parse_path)
+        .collect()
+}
+
+// ? Returns false for `none`, which clears a field (e.g. `--due none`)
+fn optional(value: &str) -> bool {
+    !value.eq_ignore_ascii_case("none")
+}
+
+// ? Builds the metadata changes from the flags (e.g. `--tag work --due none`)
+fn update(parsed: &Parsed) -> Result<TodoUpdate, Error> {
+    Ok(TodoUpdate {
+        add_tags: parsed
+            .flag_values("tag")
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        remove_tags: parsed
+            .flag_values("untag")
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        due: parsed
+            .flag_value("due")
+            .map(|due| optional(due).then(|| parse_date(due)).transpose())
+            .transpose()?,
+        priority: parsed
+            .flag_value("priority")
+            .map(|priority| {
+                optional(priority)
+                    .then(|| parse_priority(priority))
+                    .transpose()
+            })
+            .transpose()?,
+        repeat: parsed
+            .flag_value("repeat")
+            .map(|repeat| {
+                optional(repeat)
+                    .then(|| parse_recurrence(repeat))
+                    .transpose()
+            })
+            .transpose()?,
+        estimate: parsed
+            .flag_value("estimate")
+            .map(|estimate| {
+                optional(estimate)
+                    .then(|| parse_estimate(estimate))
+                    .transpose()
+            })
+            .transpose()?,
+    })
+}
+
+// ? Builds the `todo ls` filter from the search words and the flags
+fn filter(parsed: &Parsed) -> Result<Filter, Error> {
+    let done = match (parsed.has_flag("done"), parsed.has_flag("open")) {
+        (true, false) => Some(true),
+        (false, true) => Some(false),
+        _ => None, // ? Both or neither show every todo
+    };
+
+    Ok(Filter {
+        done,
+        terms: parsed.args.clone(),
+        tags: parsed
+            .flag_values("tag")
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        due_before: parsed
+            .flag_value("due-before")
+            .map(parse_date)
+            .transpose()?,
+        created_after: parsed
+            .flag_value("created-after")
+            .map(parse_date)
+            .transpose()?,
+        priority: parsed
+            .flag_value("priority")
+            .map(parse_priority)
+            .transpose()?,
+        ready: parsed.has_flag("ready"),
+    })
+}
+
+// ? Builds the `todo ls` sorting and grouping options from the flags
+fn order(parsed: &Parsed) -> Result<Order, Error> {
+    Ok(Order {
+        sort: parsed.flag_value("sort").map(parse_sort).transpose()?,
+        group: parsed.flag_value("group-by").map(parse_group).transpose()?,
+    })
 }
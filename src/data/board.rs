@@ -0,0 +1,56 @@
+use std::fs;
+use std::io::Error;
+
+use crate::data::files;
+use crate::errors;
+use crate::models::board::Board;
+
+// * Default columns of the board, the last one holds the done todos
+const DEFAULT_COLUMNS: &[&str] = &["todo", "doing", "review", "done"];
+
+// 📢 PUBLIC ----------------------------------
+
+impl Default for Board {
+    fn default() -> Self {
+        Board {
+            columns: DEFAULT_COLUMNS
+                .iter()
+                .map(|column| column.to_string())
+                .collect(),
+        }
+    }
+}
+
+// * Loads the columns of the board, or the user ones from `~/.todo/board.json`
+// ? e.g. `["backlog", "doing", "done"]`, from the first step to the done column
+pub fn load() -> Result<Board, Error> {
+    let path = files::path("board.json");
+
+    if !path.exists() {
+        return Ok(Board::default());
+    }
+
+    let content = fs::read_to_string(&path)?;
+    let columns: Vec<String> =
+        serde_json::from_str(&content).map_err(|e| errors::invalid_board(&e.to_string()))?;
+
+    if columns.len() < 2 {
+        return Err(errors::invalid_board(
+            "At least two columns are needed (e.g. todo and done)",
+        ));
+    }
+
+    for (i, column) in columns.iter().enumerate() {
+        if column.trim().is_empty() {
+            return Err(errors::invalid_board("Column names can't be empty"));
+        }
+        if columns[..i].contains(column) {
+            return Err(errors::invalid_board(&format!(
+                "The column {} is listed twice",
+                column
+            )));
+        }
+    }
+
+    Ok(Board { columns })
+}
@@ -0,0 +1,647 @@
+use crate::models::command::{Arg, ArgKind, Command, Flag, Form};
+
+// * Flag accepted by every command to show its own help (e.g. `todo add --help`)
+pub const HELP_FLAG: Flag = Flag {
+    long: "help",
+    short: Some('h'),
+    value: None,
+    description: "Show the help of the command",
+};
+
+// * Flag accepted by every command to cut long todos with `…` instead of wrapping them
+pub const TRUNCATE_FLAG: Flag = Flag {
+    long: "truncate",
+    short: None,
+    value: None,
+    description: "Cut long todos to the terminal width instead of wrapping them",
+};
+
+// * Flags accepted by every command, after its own ones
+pub const GLOBAL_FLAGS: &[Flag] = &[TRUNCATE_FLAG, HELP_FLAG];
+
+// * Define all commands
+// ? Parsing, `todo help` and `todo <command> --help` are all generated from this list
+pub const COMMANDS: &[Command] = &[
+    Command {
+        name: "",
+        aliases: &[],
+        args: &[],
+        forms: &[Form {
+            args: 0,
+            description: "List all todos",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "ls",
+        aliases: &["list", "l"],
+        args: &[TERMS],
+        forms: &[
+            Form {
+                args: 0,
+                description: "List todos matching the filter, sort and group flags",
+            },
+            Form {
+                args: 1,
+                description: "Search todos containing all the words",
+            },
+        ],
+        flags: &[
+            Flag {
+                long: "done",
+                short: Some('d'),
+                value: None,
+                description: "Only show completed todos",
+            },
+            Flag {
+                long: "open",
+                short: Some('o'),
+                value: None,
+                description: "Only show open todos",
+            },
+            Flag {
+                long: "tag",
+                short: Some('t'),
+                value: Some("<tag>"),
+                description: "Only show todos with the tag (can be repeated)",
+            },
+            Flag {
+                long: "due-before",
+                short: None,
+                value: Some("<date>"),
+                description: "Only show todos due before the date",
+            },
+            Flag {
+                long: "created-after",
+                short: None,
+                value: Some("<date>"),
+                description: "Only show todos created after the date",
+            },
+            Flag {
+                long: "priority",
+                short: Some('p'),
+                value: Some("<level>"),
+                description: "Only show todos with the priority (high, medium, low)",
+            },
+            Flag {
+                long: "sort",
+                short: Some('s'),
+                value: Some("<key>"),
+                description: "Sort by created, due, priority, alpha or status",
+            },
+            Flag {
+                long: "group-by",
+                short: Some('g'),
+                value: Some("<key>"),
+                description: "Group by status, tag, due-bucket or list (a single section)",
+            },
+            Flag {
+                long: "ready",
+                short: Some('r'),
+                value: None,
+                description: "Only show open todos that are not blocked",
+            },
+        ],
+    },
+    Command {
+        name: "add",
+        aliases: &["a"],
+        args: &[TEXT, NUMBER],
+        forms: &[
+            Form {
+                args: 1,
+                description: "Add a new todo",
+            },
+            Form {
+                args: 2,
+                description: "Add a new todo at specific position",
+            },
+        ],
+        flags: &[TAG, DUE, PRIORITY, REPEAT, ESTIMATE, UNDER],
+    },
+    Command {
+        name: "rm",
+        aliases: &["r", "remove"],
+        args: &[NUMBERS],
+        forms: &[
+            Form {
+                args: 0,
+                description: "Remove the first todo",
+            },
+            Form {
+                args: 1,
+                description: "Remove todos by number, list or range (e.g. 2 4-6)",
+            },
+        ],
+        flags: SELECTOR_FLAGS,
+    },
+    Command {
+        name: "done",
+        aliases: &["d"],
+        args: &[NUMBERS],
+        forms: &[
+            Form {
+                args: 0,
+                description: "Toggle the first todo completion status",
+            },
+            Form {
+                args: 1,
+                description: "Toggle todos completion status (e.g. 1 3 5-8)",
+            },
+        ],
+        flags: &[MATCH, FUZZY, ALL, CASCADE],
+    },
+    Command {
+        name: "clear",
+        aliases: &["c"],
+        args: &[],
+        forms: &[Form {
+            args: 0,
+            description: "Remove all todos",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "replace",
+        aliases: &["rp"],
+        args: &[NEW_TEXT, PATH],
+        forms: &[Form {
+            args: 2,
+            description: "Replace a todo's text with new text",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "edit",
+        aliases: &["e"],
+        args: &[NUMBER],
+        forms: &[
+            Form {
+                args: 0,
+                description: "Open interactive editor to navigate and edit todos",
+            },
+            Form {
+                args: 1,
+                description: "Edit a specific todo interactively",
+            },
+        ],
+        flags: &[],
+    },
+    Command {
+        name: "ui",
+        aliases: &["tui"],
+        args: &[],
+        forms: &[Form {
+            args: 0,
+            description: "Open the interactive mode to add, toggle, reorder, edit and undo",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "insert",
+        aliases: &["i"],
+        args: &[TEXT, NUMBER],
+        forms: &[Form {
+            args: 2,
+            description: "Insert a new todo at a specific position",
+        }],
+        flags: &[TAG, DUE, PRIORITY, REPEAT, ESTIMATE, UNDER],
+    },
+    Command {
+        name: "set",
+        aliases: &["s"],
+        args: &[NUMBERS],
+        forms: &[Form {
+            args: 1,
+            description: "Set the tags, due date or priority of todos",
+        }],
+        flags: &[
+            TAG,
+            Flag {
+                long: "untag",
+                short: Some('u'),
+                value: Some("<tag>"),
+                description: "Remove the tag (can be repeated)",
+            },
+            DUE,
+            PRIORITY,
+            REPEAT,
+            ESTIMATE,
+        ],
+    },
+    Command {
+        name: "est",
+        aliases: &["estimate"],
+        args: &[PATH, EFFORT],
+        forms: &[Form {
+            args: 2,
+            description: "Set the estimate of a todo (e.g. 30m, 2h, 1h30m, 3pt, or none)",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "plan",
+        aliases: &[],
+        args: &[],
+        forms: &[Form {
+            args: 0,
+            description: "Propose the open todos that fit in a capacity (e.g. plan --capacity 6h)",
+        }],
+        flags: &[Flag {
+            long: "capacity",
+            short: Some('c'),
+            value: Some("<estimate>"),
+            description: "Time or points available (e.g. 6h or 10pt)",
+        }],
+    },
+    Command {
+        name: "note",
+        aliases: &["n"],
+        args: &[PATH],
+        forms: &[Form {
+            args: 1,
+            description: "Write the notes of a todo in $EDITOR (or pipe them on stdin)",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "show",
+        aliases: &["sh"],
+        args: &[PATH],
+        forms: &[Form {
+            args: 1,
+            description: "Show a todo with its details and notes",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "block",
+        aliases: &["b"],
+        args: &[PATH],
+        forms: &[Form {
+            args: 1,
+            description: "Make a todo wait on other todos (e.g. block 3 --on 1)",
+        }],
+        flags: &[ON],
+    },
+    Command {
+        name: "unblock",
+        aliases: &["ub"],
+        args: &[PATH],
+        forms: &[Form {
+            args: 1,
+            description: "Stop a todo from waiting on other todos (all if no --on)",
+        }],
+        flags: &[ON],
+    },
+    Command {
+        name: "mv",
+        aliases: &["move"],
+        args: &[NUMBER, TO],
+        forms: &[Form {
+            args: 2,
+            description: "Move a todo to another position",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "up",
+        aliases: &[],
+        args: &[NUMBER],
+        forms: &[Form {
+            args: 1,
+            description: "Move a todo one position up",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "down",
+        aliases: &[],
+        args: &[NUMBER],
+        forms: &[Form {
+            args: 1,
+            description: "Move a todo one position down",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "top",
+        aliases: &[],
+        args: &[NUMBER],
+        forms: &[Form {
+            args: 1,
+            description: "Move a todo to the top of the list",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "bottom",
+        aliases: &[],
+        args: &[NUMBER],
+        forms: &[Form {
+            args: 1,
+            description: "Move a todo to the bottom of the list",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "start",
+        aliases: &[],
+        args: &[PATH],
+        forms: &[Form {
+            args: 1,
+            description: "Start the timer of a todo (stops the running one)",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "stop",
+        aliases: &[],
+        args: &[],
+        forms: &[Form {
+            args: 0,
+            description: "Stop the running timer",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "focus",
+        aliases: &["pomodoro"],
+        args: &[PATH],
+        forms: &[Form {
+            args: 1,
+            description: "Run a pomodoro countdown for a todo, with breaks between",
+        }],
+        flags: &[
+            Flag {
+                long: "minutes",
+                short: Some('m'),
+                value: Some("<minutes>"),
+                description: "Length of each pomodoro (default 25, up to 1440)",
+            },
+            Flag {
+                long: "break",
+                short: Some('b'),
+                value: Some("<minutes>"),
+                description: "Length of each break (default 5, up to 1440)",
+            },
+        ],
+    },
+    Command {
+        name: "report",
+        aliases: &[],
+        args: &[],
+        forms: &[Form {
+            args: 0,
+            description: "Report the time and pomodoros per todo and per tag (e.g. report --time --from 1w)",
+        }],
+        flags: &[
+            Flag {
+                long: "time",
+                short: Some('t'),
+                value: None,
+                description: "Total the time recorded with start and stop",
+            },
+            FROM,
+            Flag {
+                long: "to",
+                short: None,
+                value: Some("<date>"),
+                description: "End of the range, included (e.g. yesterday or 2026-10-31)",
+            },
+        ],
+    },
+    Command {
+        name: "agenda",
+        aliases: &[],
+        args: &[],
+        forms: &[Form {
+            args: 0,
+            description: "Show the open todos by due date (overdue, today, tomorrow, this week, later)",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "cal",
+        aliases: &["calendar"],
+        args: &[MONTH],
+        forms: &[
+            Form {
+                args: 0,
+                description: "Show this month with the number of todos due on each day",
+            },
+            Form {
+                args: 1,
+                description: "Show a month with the number of todos due on each day (e.g. cal nov or cal 2026-12)",
+            },
+        ],
+        flags: &[],
+    },
+    Command {
+        name: "board",
+        aliases: &["kanban"],
+        args: &[],
+        forms: &[Form {
+            args: 0,
+            description: "Show the todos in board columns (set them in ~/.todo/board.json)",
+        }],
+        flags: &[],
+    },
+    Command {
+        name: "stats",
+        aliases: &[],
+        args: &[],
+        forms: &[Form {
+            args: 0,
+            description: "Show counts, completion rate, activity, age and streaks",
+        }],
+        flags: &[Flag {
+            long: "json",
+            short: Some('j'),
+            value: None,
+            description: "Print the statistics as JSON (e.g. for dashboards)",
+        }],
+    },
+    Command {
+        name: "chart",
+        aliases: &[],
+        args: &[CHART],
+        forms: &[Form {
+            args: 1,
+            description: "Draw the burndown or the activity of the list (e.g. chart burndown --since 2w)",
+        }],
+        flags: &[Flag {
+            long: "since",
+            short: Some('s'),
+            value: Some("<date>"),
+            description: "First day of the chart (e.g. 2w, 30d or 2026-10-01, default 2w)",
+        }],
+    },
+    Command {
+        name: "help",
+        aliases: &["h"],
+        args: &[COMMAND],
+        forms: &[
+            Form {
+                args: 0,
+                description: "Show this help message",
+            },
+            Form {
+                args: 1,
+                description: "Show the help of a specific command",
+            },
+        ],
+        flags: &[],
+    },
+];
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Shared positional arguments
+const TEXT: Arg = Arg {
+    name: "\"text\"",
+    kind: ArgKind::Text,
+};
+
+const NEW_TEXT: Arg = Arg {
+    name: "\"new text\"",
+    kind: ArgKind::Text,
+};
+
+const NUMBER: Arg = Arg {
+    name: "<number>",
+    kind: ArgKind::Number,
+};
+
+// ? A todo number or a subtask path, see `utils::todos::parse_path`
+const PATH: Arg = Arg {
+    name: "<number>",
+    kind: ArgKind::Path,
+};
+
+const TO: Arg = Arg {
+    name: "<to>",
+    kind: ArgKind::Number,
+};
+
+// ? Numbers, ranges or `/patterns/`, see `utils::selectors`
+const NUMBERS: Arg = Arg {
+    name: "<number>...",
+    kind: ArgKind::Numbers,
+};
+
+const EFFORT: Arg = Arg {
+    name: "<estimate>",
+    kind: ArgKind::Value,
+};
+
+const CHART: Arg = Arg {
+    name: "<chart>",
+    kind: ArgKind::Text,
+};
+
+const MONTH: Arg = Arg {
+    name: "<month>",
+    kind: ArgKind::Text,
+};
+
+const TERMS: Arg = Arg {
+    name: "\"text\"...",
+    kind: ArgKind::Terms,
+};
+
+const COMMAND: Arg = Arg {
+    name: "<command>",
+    kind: ArgKind::Text,
+};
+
+// ? Shared metadata flags
+const TAG: Flag = Flag {
+    long: "tag",
+    short: Some('t'),
+    value: Some("<tag>"),
+    description: "Add the tag (can be repeated)",
+};
+
+const DUE: Flag = Flag {
+    long: "due",
+    short: Some('d'),
+    value: Some("<date>"),
+    description: "Set the due date (e.g. 2026-10-20, tomorrow, fri, +3d, or none)",
+};
+
+const PRIORITY: Flag = Flag {
+    long: "priority",
+    short: Some('p'),
+    value: Some("<level>"),
+    description: "Set the priority (high, medium, low, or none)",
+};
+
+const REPEAT: Flag = Flag {
+    long: "repeat",
+    short: Some('r'),
+    value: Some("<rule>"),
+    description: "Repeat when done (daily, weekly, mon,fri, monthly, 3d, after:3d, or none)",
+};
+
+const ESTIMATE: Flag = Flag {
+    long: "estimate",
+    short: Some('e'),
+    value: Some("<estimate>"),
+    description: "Set the estimate (e.g. 30m, 2h, 1h30m, 3pt, or none)",
+};
+
+// ? Start of a date range, see `utils::dates::parse_since`
+const FROM: Flag = Flag {
+    long: "from",
+    short: Some('f'),
+    value: Some("<date>"),
+    description: "Start of the range (e.g. 2026-10-01, yesterday, or 2w for two weeks ago)",
+};
+
+// ? Flags of the commands that target todos by text instead of number
+const SELECTOR_FLAGS: &[Flag] = &[MATCH, FUZZY, ALL];
+
+const MATCH: Flag = Flag {
+    long: "match",
+    short: Some('m'),
+    value: Some("<text>"),
+    description: "Target the todos containing the text",
+};
+
+const FUZZY: Flag = Flag {
+    long: "fuzzy",
+    short: Some('f'),
+    value: Some("<text>"),
+    description: "Target the todos best matching the text (fuzzy)",
+};
+
+const ALL: Flag = Flag {
+    long: "all",
+    short: Some('a'),
+    value: None,
+    description: "Use every match when a text selector is ambiguous",
+};
+
+// ? Subtask flags
+const UNDER: Flag = Flag {
+    long: "under",
+    short: Some('u'),
+    value: Some("<number>"),
+    description: "Add the todo as a subtask of another (e.g. 3 or 3.2)",
+};
+
+// ? Dependency flags
+const ON: Flag = Flag {
+    long: "on",
+    short: Some('o'),
+    value: Some("<number>"),
+    description: "The todo to wait on (can be repeated, e.g. 1 or 3.2)",
+};
+
+const CASCADE: Flag = Flag {
+    long: "cascade",
+    short: Some('c'),
+    value: None,
+    description: "Give the subtasks the same status as their parent",
+};
@@ -0,0 +1,439 @@
+use std::io::{Error, Stdout, Write, stdout};
+
+use crossterm::{
+    cursor::{Hide, MoveTo, Show},
+    event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{
+        self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode,
+        enable_raw_mode,
+    },
+};
+use rustyline::DefaultEditor;
+use rustyline::error::ReadlineError;
+
+use crate::data::todos::{read, write};
+use crate::data::{board, keymap, theme};
+use crate::models::editor::{Action, Editor};
+use crate::models::keymap::Resolution;
+use crate::models::todo::{Todo, TodoUpdate};
+use crate::utils::keymap::key_name;
+use crate::utils::todos::{format_path, validate_index};
+use crate::view;
+
+// ? How the editor was left, to show the right summary afterwards
+enum Exit {
+    Quit,
+    Edited(usize, String, String), // ? 1-based index, old text and new text
+    Unchanged,
+}
+
+// 📢 PUBLIC ----------------------------------
+
+// * Runs the interactive editor
+// ? `todo edit` leaves after the first text edit, `todo ui` (persistent) stays until quit
+pub fn run(index: Option<usize>, persistent: bool) -> Result<(), Error> {
+    let todos = read()?;
+
+    // Check if the todo list is empty first (the persistent mode can still add todos)
+    if todos.is_empty() && !persistent {
+        view::todos::title();
+        view::todos::empty();
+        return Ok(());
+    }
+
+    // If no index was provided, start with the first todo selected
+    let selected = match index {
+        Some(idx) => {
+            validate_index(idx, &todos)?;
+            idx - 1 // Convert to 0-based index for internal use
+        }
+        None => 0, // Start with the first todo selected
+    };
+
+    let mut editor = Editor::new(todos, selected);
+    editor.keymap = keymap::load()?;
+    editor.board = board::load()?;
+    let mode = if persistent {
+        "Interactive Mode"
+    } else {
+        "Edit Mode"
+    };
+
+    // Enter raw mode for direct key handling, on a separate screen
+    let mut stdout = stdout();
+    enable_raw_mode()?;
+    execute!(stdout, EnterAlternateScreen, Hide)?;
+
+    let result = event_loop(&mut editor, mode, persistent, &mut stdout);
+
+    // Ensure raw mode is disabled and cursor is shown before returning, even on errors
+    disable_raw_mode()?;
+    execute!(stdout, Show, LeaveAlternateScreen)?;
+
+    match result? {
+        Exit::Edited(index, old_text, new_text) => {
+            // Show the updated list with the replaced todo
+            view::todos::replaced(&[index], &old_text, &new_text)
+        }
+        Exit::Unchanged => {
+            println!("No changes made.");
+            Ok(())
+        }
+        Exit::Quit if persistent => view::todos::all(),
+        Exit::Quit => Ok(()),
+    }
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Draws the editor and handles key events until the editor is left
+fn event_loop(
+    editor: &mut Editor,
+    mode: &str,
+    persistent: bool,
+    stdout: &mut Stdout,
+) -> Result<Exit, Error> {
+    loop {
+        // TIP: The size is read on every draw, so resizing the terminal redraws to fit
+        let (width, height) = terminal::size()?;
+        let rows = view::editor::list_rows(height);
+        editor.scroll_into_view(rows);
+        view::editor::render(stdout, editor, mode, width, height)?;
+
+        let key = match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => continue, // ? Resize events just redraw
+        };
+
+        editor.status = None;
+
+        // While the search prompt is open, keys edit the query instead of running actions
+        // TIP: Enter on a match goes straight to editing it
+        let action = if editor.is_searching() {
+            match search_key(editor, key) {
+                Some(action) => action,
+                None => continue,
+            }
+        } else {
+            match action_for(editor, key) {
+                Some(action) => action,
+                None => continue,
+            }
+        };
+
+        // While a todo is grabbed, the selection keys move it instead
+        if editor.grabbed.is_some() {
+            grab_action(editor, action, rows)?;
+            continue;
+        }
+
+        match action {
+            Action::Up => editor.up(),
+            Action::Down => editor.down(),
+            Action::PageUp => editor.page_up(rows),
+            Action::PageDown => editor.page_down(rows),
+            Action::Home => editor.home(),
+            Action::End => editor.end(),
+            Action::MoveUp | Action::MoveDown => {
+                let before = editor.todos.clone();
+                if editor.move_targets(action == Action::MoveUp) {
+                    editor.history.push(before);
+                    write(&editor.todos)?;
+                }
+            }
+            Action::Mark => {
+                editor.toggle_mark();
+                editor.down();
+            }
+            Action::Visual => editor.toggle_visual(),
+            Action::Grab => {
+                editor.grab();
+                editor.status = Some("Moving, press m or Enter to drop, Esc to cancel".to_string());
+            }
+            Action::Toggle => {
+                let targets = editor.targets();
+                let mut status = if let [index] = targets[..] {
+                    let status = if editor.todos[index].done {
+                        "open"
+                    } else {
+                        "done"
+                    };
+                    format!("Marked as {}", status)
+                } else {
+                    format!("Toggled {} todos", targets.len())
+                };
+
+                // NOTE: The whole batch is written once, so it is undone as a unit
+                if !targets.is_empty() {
+                    editor.snapshot();
+                    let (created, unblocked) = editor.toggle()?;
+                    editor.clear_selection();
+                    write(&editor.todos)?;
+
+                    // Mention what else changed, as `todo done` does
+                    if !created.is_empty() {
+                        status.push_str(&format!(", next as {}", numbers(&created)));
+                    }
+                    if !unblocked.is_empty() {
+                        status.push_str(&format!(", unblocked {}", numbers(&unblocked)));
+                    }
+                    editor.status = Some(status);
+                }
+            }
+            Action::ColumnLeft | Action::ColumnRight => {
+                let before = editor.todos.clone();
+                let moved = editor.move_column(action == Action::ColumnRight);
+                if moved > 0 {
+                    editor.history.push(before);
+                    editor.clear_selection();
+                    write(&editor.todos)?;
+
+                    let column = editor.board.column_of(&editor.todos[editor.selected]);
+                    editor.status = match moved {
+                        1 => Some(format!("Moved to {}", editor.board.columns[column])),
+                        _ => Some(format!("Moved {} todos", moved)),
+                    };
+                }
+            }
+            Action::Delete => {
+                let before = editor.todos.clone();
+                let deleted = editor.delete();
+                if !deleted.is_empty() {
+                    editor.history.push(before);
+                    write(&editor.todos)?;
+                    editor.status = match &deleted[..] {
+                        [todo] => Some(format!("Deleted \"{}\"", todo.text)),
+                        _ => Some(format!("Deleted {} todos", deleted.len())),
+                    };
+                }
+            }
+            Action::Tag => {
+                if editor.todos.is_empty() {
+                    continue;
+                }
+                if let Some(tag) = prompt(
+                    stdout,
+                    theme::current().status.paint("Tag:").to_string(),
+                    "",
+                )? {
+                    let update = TodoUpdate {
+                        add_tags: vec![tag.trim_start_matches('#').to_string()],
+                        ..TodoUpdate::default()
+                    };
+                    editor.snapshot();
+                    let count = editor.update(&update);
+                    editor.clear_selection();
+                    write(&editor.todos)?;
+                    editor.status = Some(format!("Tagged {} todo(s)", count));
+                }
+            }
+            Action::Add => {
+                if let Some(text) = prompt(
+                    stdout,
+                    theme::current().status.paint("Add todo:").to_string(),
+                    "",
+                )? {
+                    editor.snapshot();
+                    editor.insert_below(Todo::new(&text));
+                    write(&editor.todos)?;
+                    editor.status = Some("Added".to_string());
+                }
+            }
+            Action::Edit => {
+                let Some(todo) = editor.todos.get(editor.selected) else {
+                    continue;
+                };
+                let old_text = todo.text.clone();
+
+                let prompt_text = format!(
+                    "{} {}{}",
+                    theme::current().status.paint("Edit todo"),
+                    theme::current()
+                        .index
+                        .paint((editor.selected + 1).to_string()),
+                    theme::current().status.paint(":")
+                );
+
+                // Pre-populate the input with the current todo text
+                let new_text = prompt(stdout, prompt_text, &old_text)?;
+
+                // If the user just presses Enter without entering text, keep the original text
+                match new_text {
+                    Some(new_text) if new_text != old_text => {
+                        editor.snapshot();
+                        editor.todos[editor.selected].text = new_text.clone();
+                        write(&editor.todos)?;
+                        editor.status = Some("Edited".to_string());
+
+                        if !persistent {
+                            return Ok(Exit::Edited(editor.selected + 1, old_text, new_text));
+                        }
+                    }
+                    _ if !persistent => return Ok(Exit::Unchanged),
+                    _ => {}
+                }
+            }
+            Action::Search => {
+                editor.clear_selection();
+                editor.start_search();
+            }
+            Action::NextMatch | Action::PrevMatch => {
+                if editor.search.is_none() {
+                    editor.status = Some("No search yet, press / to search".to_string());
+                } else if !editor.next_match(action == Action::NextMatch) {
+                    editor.status = Some("No match".to_string());
+                }
+            }
+            Action::Undo => {
+                if editor.undo() {
+                    write(&editor.todos)?;
+                    editor.status = Some("Undone".to_string());
+                } else {
+                    editor.status = Some("Nothing to undo".to_string());
+                }
+            }
+            // TIP: Quitting after a search (or with marked todos) clears it first
+            Action::Quit if editor.search.is_some() => editor.cancel_search(),
+            Action::Quit if editor.has_selection() => {
+                editor.clear_selection();
+                editor.status = Some("Selection cleared".to_string());
+            }
+            Action::Quit => return Ok(Exit::Quit),
+        }
+    }
+}
+
+// ? Handles an action while a todo is grabbed, writing the list once when it is dropped
+fn grab_action(editor: &mut Editor, action: Action, rows: usize) -> Result<(), Error> {
+    match action {
+        Action::Up | Action::MoveUp => {
+            editor.move_selected(true);
+        }
+        Action::Down | Action::MoveDown => {
+            editor.move_selected(false);
+        }
+        Action::PageUp => editor.move_selected_to(editor.selected.saturating_sub(rows.max(1))),
+        Action::PageDown => editor.move_selected_to(editor.selected + rows.max(1)),
+        Action::Home => editor.move_selected_to(0),
+        Action::End => editor.move_selected_to(editor.todos.len()),
+        Action::Grab | Action::Edit => match editor.drop_grabbed() {
+            Some(before) => {
+                editor.history.push(before);
+                write(&editor.todos)?;
+                editor.status = Some(format!("Moved to {}", editor.selected + 1));
+            }
+            None => editor.status = Some("Not moved".to_string()),
+        },
+        Action::Quit => {
+            // TIP: Esc (or any quit key) cancels the move instead of leaving
+            editor.cancel_grab();
+            editor.status = Some("Move cancelled".to_string());
+        }
+        _ => {
+            editor.status = Some("Drop the todo first (m or Enter)".to_string());
+        }
+    }
+
+    Ok(())
+}
+
+// ? Edits the search query with a key press, filtering the list as it changes
+// ? Enter keeps the selected match and returns the edit action for it, Esc puts the selection back
+fn search_key(editor: &mut Editor, key: KeyEvent) -> Option<Action> {
+    let query = editor.search.as_ref().map(|search| search.query.clone())?;
+
+    match key.code {
+        KeyCode::Enter if editor.confirm_search() => return Some(Action::Edit),
+        KeyCode::Enter => {
+            editor.status = Some(format!("No match for \"{}\"", query));
+        }
+        KeyCode::Esc => editor.cancel_search(),
+        KeyCode::Up => {
+            editor.next_match(false);
+        }
+        KeyCode::Down | KeyCode::Tab => {
+            editor.next_match(true);
+        }
+        KeyCode::Backspace => {
+            let mut query = query;
+            query.pop();
+            editor.set_query(query);
+        }
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+            editor.cancel_search();
+        }
+        KeyCode::Char(c) => editor.set_query(format!("{}{}", query, c)),
+        _ => {}
+    }
+
+    None
+}
+
+// ? Lists todo numbers for the status bar (e.g. `4, 6`)
+fn numbers(paths: &[Vec<usize>]) -> String {
+    paths
+        .iter()
+        .map(|path| format_path(path))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+// ? Maps a key press to an editor action, waiting for the rest of multi-key bindings
+fn action_for(editor: &mut Editor, key: KeyEvent) -> Option<Action> {
+    let name = key_name(key)?;
+
+    // TIP: Esc cancels a pending multi-key binding instead of quitting
+    if name == "Esc" && !editor.pending.is_empty() {
+        editor.pending.clear();
+        return None;
+    }
+
+    editor.pending.push(name);
+
+    match editor.keymap.resolve(&editor.pending) {
+        Resolution::Action(action) => {
+            editor.pending.clear();
+            Some(action)
+        }
+        Resolution::Pending => {
+            editor.status = Some(format!("{}…", editor.pending.concat()));
+            None
+        }
+        Resolution::Unknown => {
+            editor.status = Some(format!("Unknown key: {}", editor.pending.concat()));
+            editor.pending.clear();
+            None
+        }
+    }
+}
+
+// ? Reads a line of text at the bottom of the screen, pre-populated with `initial`
+// ? Returns `None` if the input is cancelled or empty
+fn prompt(stdout: &mut Stdout, label: String, initial: &str) -> Result<Option<String>, Error> {
+    let (_, height) = terminal::size()?;
+
+    // Leave raw mode so the line editor can handle the input
+    disable_raw_mode()?;
+    execute!(
+        stdout,
+        MoveTo(0, height.saturating_sub(1)),
+        Clear(ClearType::CurrentLine),
+        Show
+    )?;
+    stdout.flush()?;
+
+    let mut rl = DefaultEditor::new()
+        .map_err(|e| Error::other(format!("Failed to initialize editor: {}", e)))?;
+
+    let input = rl.readline_with_initial(&format!("{} ", label), (initial, ""));
+
+    enable_raw_mode()?;
+    execute!(stdout, Hide)?;
+
+    match input {
+        Ok(text) if !text.trim().is_empty() => Ok(Some(text.trim().to_string())),
+        Ok(_) | Err(ReadlineError::Interrupted | ReadlineError::Eof) => Ok(None),
+        Err(e) => Err(Error::other(format!("Failed to read input: {}", e))),
+    }
+}
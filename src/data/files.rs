@@ -0,0 +1,17 @@
+use std::fs;
+use std::path::PathBuf;
+
+// * Returns the path of a file in the app directory (e.g. `~/.todo/todos.json`)
+pub fn path(filename: &str) -> PathBuf {
+    // Get the home directory
+    let mut path = dirs::home_dir().expect("Could not find home directory");
+
+    // NOTE: Choose the directory where the app files are stored
+    path.push(".todo");
+
+    // Create the directory if it doesn't exist
+    fs::create_dir_all(&path).expect("Could not create directory: .todo/");
+
+    path.push(filename); // Append the filename to the path
+    path // Return the full path to the file
+}
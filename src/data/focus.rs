@@ -0,0 +1,150 @@
+use std::io::{Error, Stdout, Write, stdout};
+use std::time::Duration;
+
+use chrono::Local;
+use crossterm::{
+    cursor::{Hide, Show},
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{
+        self, EnterAlternateScreen, LeaveAlternateScreen, disable_raw_mode, enable_raw_mode,
+    },
+};
+
+use crate::data::todos::{read, toggle, write};
+use crate::models::focus::{Focus, Phase};
+use crate::models::selector::Selection;
+use crate::models::todo::Todo;
+use crate::utils::terminal::{confirm, is_interactive};
+use crate::utils::todos::{find, find_mut, find_running, format_path, validate_path};
+use crate::view;
+
+// * How often the countdown is redrawn while no key is pressed
+const TICK: Duration = Duration::from_millis(250);
+
+// 📢 PUBLIC ----------------------------------
+
+// * Runs a full-screen pomodoro countdown for a todo (e.g. `todo focus 2 --minutes 25`)
+// ? The todo's timer runs during the work phases, so the focused time shows in `todo report --time`
+pub fn run(path: &[usize], work: Duration, rest: Duration) -> Result<(), Error> {
+    let mut todos = read()?;
+
+    // Check if the todo list is empty first
+    if todos.is_empty() {
+        view::todos::title();
+        view::todos::empty();
+        return Ok(());
+    }
+
+    validate_path(path, &todos)?;
+
+    // Only one timer runs at a time, so stop the one running on another todo
+    if let Some(running) = find_running(&todos) {
+        find_mut(&mut todos, &running)
+            .expect("The path was found")
+            .stop_timer();
+    }
+
+    let mut focus = Focus::new(work, rest);
+
+    // Enter raw mode for direct key handling, on a separate screen
+    let mut stdout = stdout();
+    enable_raw_mode()?;
+    execute!(stdout, EnterAlternateScreen, Hide)?;
+
+    let result = event_loop(&mut focus, &mut todos, path, &mut stdout);
+
+    // Ensure raw mode is disabled and cursor is shown before returning, even on errors
+    disable_raw_mode()?;
+    execute!(stdout, Show, LeaveAlternateScreen)?;
+
+    // The session is over, so the timer stops with it
+    let todo = find_mut(&mut todos, path).expect("The path was validated");
+    todo.stop_timer();
+    let done = todo.done;
+    let text = todo.text.clone();
+    write(&todos)?;
+
+    result?;
+
+    // Offer to mark the todo as done, as a session often ends with the task
+    let question = format!("Mark {} {} as done?", format_path(path), text);
+    if !done && is_interactive() && confirm(&question)? {
+        toggle(&Selection::path(path), false)?;
+    } else {
+        view::todos::updated(&[path.to_vec()])?;
+    }
+
+    view::focus::summary(&focus);
+
+    Ok(())
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Draws the countdown and handles key events until the session is left
+fn event_loop(
+    focus: &mut Focus,
+    todos: &mut Vec<Todo>,
+    path: &[usize],
+    stdout: &mut Stdout,
+) -> Result<(), Error> {
+    let todo = find(todos, path).expect("The path was validated");
+    let label = format!("{} {}", format_path(path), todo.text);
+
+    loop {
+        // A phase that ran out logs its pomodoro and starts the next one, with a bell
+        if focus.is_over() {
+            if focus.phase == Phase::Work {
+                let todo = find_mut(todos, path).expect("The path was validated");
+                todo.pomodoros.push(Local::now());
+                write(todos)?;
+            }
+            focus.next_phase(true);
+            write!(stdout, "\x07")?;
+        }
+
+        sync_timer(focus, todos, path)?;
+
+        // TIP: The size is read on every draw, so resizing the terminal redraws to fit
+        let (width, height) = terminal::size()?;
+        view::focus::render(stdout, focus, &label, width, height)?;
+
+        // Wait for a key, or redraw the countdown after a tick
+        if !event::poll(TICK)? {
+            continue;
+        }
+        let key = match event::read()? {
+            Event::Key(key) if key.kind == KeyEventKind::Press => key,
+            _ => continue, // ? Resize events just redraw
+        };
+
+        match key.code {
+            KeyCode::Char(' ') | KeyCode::Char('p') => focus.toggle_pause(),
+            KeyCode::Char('s') => focus.next_phase(false),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(()),
+            KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+            _ => {}
+        }
+    }
+}
+
+// ? Keeps the todo's timer running only while working (not during breaks or pauses)
+fn sync_timer(focus: &Focus, todos: &mut Vec<Todo>, path: &[usize]) -> Result<(), Error> {
+    let todo = find_mut(todos, path).expect("The path was validated");
+
+    let changed = match (focus.is_working(), todo.running().is_some()) {
+        (true, false) => {
+            todo.start_timer();
+            true
+        }
+        (false, true) => todo.stop_timer().is_some(),
+        _ => false,
+    };
+
+    if changed {
+        write(todos)?;
+    }
+
+    Ok(())
+}
@@ -1,139 +1,68 @@
+use crate::data::commands::COMMANDS;
+use crate::models::command::{Command, Form};
 use crate::models::help::HelpCommand;
 
 // * Define all help commands
+// ? Generated from the command definitions so the help matches what is accepted
 pub fn get_commands() -> Vec<HelpCommand> {
-    vec![
-        HelpCommand {
-            command: "todo".to_string(),
-            description: "List all todos".to_string(),
-            command_text: None,
-            command_arg: None,
-        },
-        HelpCommand {
-            command: "todo add".to_string(),
-            description: "Add a new todo".to_string(),
-            command_text: Some("\"text\"".to_string()),
-            command_arg: None,
-        },
-        HelpCommand {
-            command: "todo add".to_string(),
-            description: "Add a new todo at specific position".to_string(),
-            command_text: Some("\"text\"".to_string()),
-            command_arg: Some("<number>".to_string()),
-        },
-        HelpCommand {
-            command: "todo rm".to_string(),
-            description: "Remove the first todo".to_string(),
-            command_text: None,
-            command_arg: None,
-        },
-        HelpCommand {
-            command: "todo rm".to_string(),
-            description: "Remove a specific todo by number".to_string(),
-            command_text: None,
-            command_arg: Some("<number>".to_string()),
-        },
-        HelpCommand {
-            command: "todo done".to_string(),
-            description: "Toggle the first todo completion status".to_string(),
-            command_text: None,
-            command_arg: None,
-        },
-        HelpCommand {
-            command: "todo done".to_string(),
-            description: "Toggle todo completion status".to_string(),
-            command_text: None,
-            command_arg: Some("<number>".to_string()),
-        },
-        HelpCommand {
-            command: "todo clear".to_string(),
-            description: "Remove all todos".to_string(),
-            command_text: None,
-            command_arg: None,
-        },
-        HelpCommand {
-            command: "todo replace".to_string(),
-            description: "Replace a todo's text with new text".to_string(),
-            command_text: Some("\"new text\"".to_string()),
-            command_arg: Some("<number>".to_string()),
-        },
-        HelpCommand {
-            command: "todo edit".to_string(),
-            description: "Open interactive editor to navigate and edit todos".to_string(),
-            command_text: None,
-            command_arg: None,
-        },
-        HelpCommand {
-            command: "todo edit".to_string(),
-            description: "Edit a specific todo interactively".to_string(),
-            command_text: None,
-            command_arg: Some("<number>".to_string()),
-        },
-        HelpCommand {
-            command: "todo insert".to_string(),
-            description: "Insert a new todo at a specific position".to_string(),
-            command_text: Some("\"text\"".to_string()),
-            command_arg: Some("<number>".to_string()),
-        },
-        HelpCommand {
-            command: "todo help".to_string(),
-            description: "Show this help message".to_string(),
-            command_text: None,
-            command_arg: None,
-        },
-    ]
+    COMMANDS
+        .iter()
+        .flat_map(|cmd| cmd.forms.iter().map(move |form| form_help(cmd, form)))
+        .collect()
 }
 
 // * Define all help aliases (e.g. `a` for `add`, `r` for `remove`, etc.)
 pub fn get_aliases() -> Vec<HelpCommand> {
-    vec![
-        HelpCommand {
-            command: "todo a".to_string(),
-            description: "Alias for `todo add`".to_string(),
-            command_text: None,
-            command_arg: None,
-        },
-        HelpCommand {
-            command: "todo r, remove".to_string(),
-            description: "Aliases for `todo rm`".to_string(),
-            command_text: None,
-            command_arg: None,
-        },
-        HelpCommand {
-            command: "todo d".to_string(),
-            description: "Alias for `todo done`".to_string(),
-            command_text: None,
-            command_arg: None,
-        },
-        HelpCommand {
-            command: "todo c".to_string(),
-            description: "Alias for `todo clear`".to_string(),
-            command_text: None,
-            command_arg: None,
-        },
-        HelpCommand {
-            command: "todo rp".to_string(),
-            description: "Alias for `todo replace`".to_string(),
-            command_text: None,
-            command_arg: None,
-        },
-        HelpCommand {
-            command: "todo i".to_string(),
-            description: "Alias for `todo insert`".to_string(),
-            command_text: None,
-            command_arg: None,
-        },
-        HelpCommand {
-            command: "todo h".to_string(),
-            description: "Alias for `todo help`".to_string(),
-            command_text: None,
-            command_arg: None,
-        },
-        HelpCommand {
-            command: "todo e".to_string(),
-            description: "Alias for `todo edit`".to_string(),
-            command_text: None,
-            command_arg: None,
-        },
-    ]
+    COMMANDS
+        .iter()
+        .filter(|cmd| !cmd.aliases.is_empty())
+        .map(|cmd| {
+            let description = if cmd.aliases.len() == 1 {
+                format!("Alias for `{}`", cmd.usage_name())
+            } else {
+                format!("Aliases for `{}`", cmd.usage_name())
+            };
+
+            HelpCommand {
+                command: format!("todo {}", cmd.aliases.join(", ")),
+                description,
+                command_text: None,
+                command_arg: None,
+            }
+        })
+        .collect()
+}
+
+// * Define the help lines of a single command (e.g. `todo add --help`)
+pub fn get_command_forms(cmd: &Command) -> Vec<HelpCommand> {
+    cmd.forms.iter().map(|form| form_help(cmd, form)).collect()
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Builds the help line of a single command form
+fn form_help(cmd: &Command, form: &Form) -> HelpCommand {
+    let args = &cmd.args[..form.args];
+
+    // Join either the text placeholders (e.g. `"text"`) or the number ones (e.g. `<number>`)
+    let placeholders = |text: bool| {
+        let names: Vec<&str> = args
+            .iter()
+            .filter(|arg| arg.kind.is_text() == text)
+            .map(|arg| arg.name)
+            .collect();
+
+        if names.is_empty() {
+            None
+        } else {
+            Some(names.join(" "))
+        }
+    };
+
+    HelpCommand {
+        command: cmd.usage_name(),
+        description: form.description.to_string(),
+        command_text: placeholders(true),
+        command_arg: placeholders(false),
+    }
 }
@@ -0,0 +1,79 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Error;
+
+use crate::data::files;
+use crate::errors;
+use crate::models::editor::Action;
+use crate::models::keymap::Keymap;
+use crate::utils::keymap::parse_keys;
+
+// * Default key bindings of the interactive editor (arrows plus vim-style keys)
+const DEFAULT_BINDINGS: &[(Action, &[&str])] = &[
+    (Action::Up, &["<Up>", "k"]),
+    (Action::Down, &["<Down>", "j"]),
+    (Action::PageUp, &["<PageUp>", "<C-b>"]),
+    (Action::PageDown, &["<PageDown>", "<C-f>"]),
+    (Action::Home, &["<Home>", "gg"]),
+    (Action::End, &["<End>", "G"]),
+    (Action::MoveUp, &["<S-Up>", "K"]),
+    (Action::MoveDown, &["<S-Down>", "J"]),
+    (Action::Grab, &["m"]),
+    (Action::Mark, &["<Space>"]),
+    (Action::Visual, &["v"]),
+    (Action::Tag, &["t"]),
+    (Action::Edit, &["<Enter>"]),
+    (Action::Add, &["o", "a"]),
+    (Action::Toggle, &["x"]),
+    (Action::ColumnLeft, &["<Left>", "h"]),
+    (Action::ColumnRight, &["<Right>", "l"]),
+    (Action::Delete, &["dd"]),
+    (Action::Search, &["/"]),
+    (Action::NextMatch, &["n"]),
+    (Action::PrevMatch, &["N"]),
+    (Action::Undo, &["u"]),
+    (Action::Quit, &["q", "<Esc>", "<C-c>"]),
+];
+
+// 📢 PUBLIC ----------------------------------
+
+impl Default for Keymap {
+    fn default() -> Self {
+        let bindings = DEFAULT_BINDINGS
+            .iter()
+            .flat_map(|(action, keys)| {
+                keys.iter().map(move |keys| {
+                    let keys = parse_keys(keys).expect("Default key bindings must be valid");
+                    (keys, *action)
+                })
+            })
+            .collect();
+
+        Keymap { bindings }
+    }
+}
+
+// * Loads the key bindings, with the user overrides from `~/.todo/keymap.json`
+// ? e.g. `{ "toggle": ["<Space>"], "delete": ["D"] }` replaces the keys of those actions only
+pub fn load() -> Result<Keymap, Error> {
+    let mut keymap = Keymap::default();
+    let path = files::path("keymap.json");
+
+    if !path.exists() {
+        return Ok(keymap);
+    }
+
+    let content = fs::read_to_string(&path)?;
+    let overrides: HashMap<Action, Vec<String>> =
+        serde_json::from_str(&content).map_err(|e| errors::invalid_keymap(&e.to_string()))?;
+
+    for (action, keys) in overrides {
+        keymap.bindings.retain(|(_, bound)| *bound != action);
+
+        for keys in keys {
+            keymap.bindings.push((parse_keys(&keys)?, action));
+        }
+    }
+
+    Ok(keymap)
+}
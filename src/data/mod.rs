@@ -1,3 +1,11 @@
+pub mod board;
+pub mod commands;
+pub mod editor;
+pub mod files;
+pub mod focus;
 pub mod help;
+pub mod keymap;
+pub mod notes;
+pub mod theme;
 pub mod timer;
 pub mod todos;
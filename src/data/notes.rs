@@ -0,0 +1,57 @@
+use std::env;
+use std::fs;
+use std::io::{Error, IsTerminal, Read, stdin};
+use std::process::Command;
+
+use crate::errors;
+
+// 📢 PUBLIC ----------------------------------
+
+// * Reads a note from stdin if it is piped (e.g. `cat steps.md | todo note 3`),
+// * or lets the user write it in their editor, starting from the current note
+// ? Returns `None` if the note is left empty
+pub fn read_note(current: &str) -> Result<Option<String>, Error> {
+    let note = if stdin().is_terminal() {
+        edit_in_editor(current)?
+    } else {
+        let mut note = String::new();
+        stdin().read_to_string(&mut note)?;
+        note
+    };
+
+    let note = note.trim_end();
+    Ok((!note.trim().is_empty()).then(|| note.to_string()))
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Opens `$VISUAL` or `$EDITOR` (`vi` if neither is set) on a temporary file with the note
+fn edit_in_editor(current: &str) -> Result<String, Error> {
+    let editor = env::var("VISUAL")
+        .or_else(|_| env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+
+    let path = env::temp_dir().join(format!("todo-note-{}.md", std::process::id()));
+    // Start on a new line after the current note, as editors expect
+    let content = if current.is_empty() {
+        String::new()
+    } else {
+        format!("{}\n", current)
+    };
+    fs::write(&path, content)?;
+
+    // TIP: The editor can come with arguments (e.g. `code --wait`)
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or("vi");
+    let status = Command::new(program).args(words).arg(&path).status();
+
+    let note = match status {
+        Ok(status) if status.success() => fs::read_to_string(&path),
+        Ok(status) => Err(errors::editor_failed(&editor, &status.to_string())),
+        Err(e) => Err(errors::editor_failed(&editor, &e.to_string())),
+    };
+
+    // Remove the temporary file whether the editor succeeded or not
+    let _ = fs::remove_file(&path);
+    note
+}
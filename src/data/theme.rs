@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+use std::fs;
+use std::io::Error;
+use std::sync::OnceLock;
+
+use colored::Color;
+
+use crate::data::files;
+use crate::errors;
+use crate::models::theme::{Style, Theme};
+use crate::utils::theme::parse_style;
+
+// * Names of the built-in themes, the first one is the default
+pub const THEMES: &[&str] = &["dark", "light", "high-contrast"];
+
+// * The theme of this run, loaded once before the command runs
+static THEME: OnceLock<Theme> = OnceLock::new();
+
+// 📢 PUBLIC ----------------------------------
+
+impl Default for Theme {
+    fn default() -> Self {
+        dark()
+    }
+}
+
+// * Loads the theme from `~/.todo/theme.json` for the rest of the run
+// ? A broken theme file only warns and keeps the default theme, so commands and the help still run
+pub fn init() {
+    let theme = load().unwrap_or_else(|e| {
+        errors::warning(e);
+        Theme::default()
+    });
+    let _ = THEME.set(theme);
+}
+
+// * Returns the theme of this run, or the default one if it wasn't loaded
+pub fn current() -> &'static Theme {
+    THEME.get_or_init(Theme::default)
+}
+
+// * Returns a built-in theme by its name (e.g. `light`)
+pub fn built_in(name: &str) -> Option<Theme> {
+    match name {
+        "dark" => Some(dark()),
+        "light" => Some(light()),
+        "high-contrast" => Some(high_contrast()),
+        _ => None,
+    }
+}
+
+// * Loads the theme, or the user one from `~/.todo/theme.json`
+// ? e.g. `{ "base": "light", "index": "bold blue", "open_mark": "○" }` changes those roles of the light theme
+pub fn load() -> Result<Theme, Error> {
+    let path = files::path("theme.json");
+
+    if !path.exists() {
+        return Ok(Theme::default());
+    }
+
+    let content = fs::read_to_string(&path)?;
+    let mut overrides: HashMap<String, String> =
+        serde_json::from_str(&content).map_err(|e| errors::invalid_theme(&e.to_string()))?;
+
+    let mut theme = match overrides.remove("base") {
+        Some(base) => built_in(&base).ok_or_else(|| {
+            errors::invalid_theme(&format!(
+                "Unknown theme {} (use {})",
+                base,
+                THEMES.join(", ")
+            ))
+        })?,
+        None => Theme::default(),
+    };
+
+    for (role, value) in overrides {
+        match role.as_str() {
+            "open_mark" | "done_mark" => {
+                if value.trim().is_empty() {
+                    return Err(errors::invalid_theme(&format!("{} can't be empty", role)));
+                }
+                if role == "open_mark" {
+                    theme.open_mark = value;
+                } else {
+                    theme.done_mark = value;
+                }
+            }
+            role => *style_of(&mut theme, role)? = parse_style(&value)?,
+        }
+    }
+
+    Ok(theme)
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? The original colors, made for dark backgrounds
+fn dark() -> Theme {
+    Theme {
+        index: Style::color(Color::Magenta),
+        open: Style::color(Color::Blue),
+        done: Style::color(Color::Green),
+        added: Style::color(Color::Cyan),
+        removed: Style::color(Color::Red),
+        toggled: Style::color(Color::Yellow),
+        title: Style {
+            bold: true,
+            ..Style::default()
+        },
+        hint: Style {
+            dimmed: true,
+            ..Style::default()
+        },
+        command: Style {
+            bold: true,
+            ..Style::color(Color::Cyan)
+        },
+        argument: Style::color(Color::Cyan),
+        high: Style {
+            bold: true,
+            ..Style::color(Color::Red)
+        },
+        medium: Style::color(Color::Yellow),
+        low: Style::color(Color::Blue),
+        overdue: Style::color(Color::Red),
+        due: Style::color(Color::Yellow),
+        tag: Style {
+            dimmed: true,
+            ..Style::color(Color::Cyan)
+        },
+        status: Style::color(Color::Yellow),
+        timer: Style::color(Color::Yellow),
+        highlight: Style {
+            bold: true,
+            underline: true,
+            ..Style::color(Color::Yellow)
+        },
+        selected: Style {
+            background: Some(Color::Blue),
+            ..Style::color(Color::White)
+        },
+        grabbed: Style {
+            background: Some(Color::Yellow),
+            ..Style::color(Color::Black)
+        },
+        open_mark: "☐".to_string(),
+        done_mark: "✔︎".to_string(),
+    }
+}
+
+// ? Darker colors for light backgrounds, without the yellow and cyan that fade on white
+fn light() -> Theme {
+    Theme {
+        index: Style::color(Color::Magenta),
+        open: Style::color(Color::Blue),
+        done: Style::color(Color::Green),
+        added: Style {
+            bold: true,
+            ..Style::color(Color::Blue)
+        },
+        removed: Style::color(Color::Red),
+        toggled: Style {
+            bold: true,
+            ..Style::color(Color::Magenta)
+        },
+        title: Style {
+            bold: true,
+            ..Style::color(Color::Black)
+        },
+        hint: Style::color(Color::BrightBlack),
+        command: Style {
+            bold: true,
+            ..Style::color(Color::Blue)
+        },
+        argument: Style::color(Color::Magenta),
+        medium: Style::color(Color::Magenta),
+        due: Style::color(Color::Magenta),
+        tag: Style::color(Color::Blue),
+        status: Style::color(Color::Magenta),
+        timer: Style::color(Color::Magenta),
+        highlight: Style {
+            bold: true,
+            underline: true,
+            ..Style::color(Color::Magenta)
+        },
+        grabbed: Style {
+            background: Some(Color::Magenta),
+            ..Style::color(Color::White)
+        },
+        ..dark()
+    }
+}
+
+// ? Bright bold colors and ASCII marks that read on any background, with nothing dimmed
+fn high_contrast() -> Theme {
+    let bold = |color: Color| Style {
+        bold: true,
+        ..Style::color(color)
+    };
+
+    Theme {
+        index: bold(Color::BrightMagenta),
+        open: bold(Color::BrightBlue),
+        done: bold(Color::BrightGreen),
+        added: bold(Color::BrightCyan),
+        removed: bold(Color::BrightRed),
+        toggled: bold(Color::BrightYellow),
+        title: Style {
+            bold: true,
+            ..Style::default()
+        },
+        hint: Style::default(),
+        command: bold(Color::BrightCyan),
+        argument: Style::color(Color::BrightYellow),
+        high: bold(Color::BrightRed),
+        medium: bold(Color::BrightYellow),
+        low: bold(Color::BrightBlue),
+        overdue: bold(Color::BrightRed),
+        due: bold(Color::BrightYellow),
+        tag: bold(Color::BrightCyan),
+        status: bold(Color::BrightYellow),
+        timer: bold(Color::BrightYellow),
+        highlight: Style {
+            underline: true,
+            ..bold(Color::BrightYellow)
+        },
+        selected: Style {
+            background: Some(Color::Blue),
+            ..bold(Color::BrightWhite)
+        },
+        grabbed: Style {
+            background: Some(Color::BrightYellow),
+            ..bold(Color::Black)
+        },
+        open_mark: "[ ]".to_string(),
+        done_mark: "[x]".to_string(),
+    }
+}
+
+// ? Returns the style of a role by its name in the theme file
+fn style_of<'a>(theme: &'a mut Theme, role: &str) -> Result<&'a mut Style, Error> {
+    let style = match role {
+        "index" => &mut theme.index,
+        "open" => &mut theme.open,
+        "done" => &mut theme.done,
+        "added" => &mut theme.added,
+        "removed" => &mut theme.removed,
+        "toggled" => &mut theme.toggled,
+        "title" => &mut theme.title,
+        "hint" => &mut theme.hint,
+        "command" => &mut theme.command,
+        "argument" => &mut theme.argument,
+        "high" => &mut theme.high,
+        "medium" => &mut theme.medium,
+        "low" => &mut theme.low,
+        "overdue" => &mut theme.overdue,
+        "due" => &mut theme.due,
+        "tag" => &mut theme.tag,
+        "status" => &mut theme.status,
+        "timer" => &mut theme.timer,
+        "highlight" => &mut theme.highlight,
+        "selected" => &mut theme.selected,
+        "grabbed" => &mut theme.grabbed,
+        _ => return Err(errors::invalid_theme(&format!("Unknown role {}", role))),
+    };
+    Ok(style)
+}
@@ -2,11 +2,16 @@ use std::fs;
 use std::io::{Error, ErrorKind};
 use std::path::PathBuf;
 
-use colored::Colorize;
-use rustyline::DefaultEditor;
-
-use crate::models::todo::Todo;
-use crate::utils::todos::{validate_index, validate_index_on_add};
+use crate::data::{editor, files, notes};
+use crate::errors;
+use crate::models::selector::Selection;
+use crate::models::todo::{Destination, Todo, TodoUpdate};
+use crate::utils;
+use crate::utils::selectors::resolve;
+use crate::utils::todos::{
+    depends_on, ensure_id, find, find_mut, find_running, format_path, mark_blocked, siblings_mut,
+    validate_index, validate_index_on_add, validate_path,
+};
 use crate::view;
 
 // 📢 PUBLIC ----------------------------------
@@ -21,13 +26,15 @@ pub fn read() -> Result<Vec<Todo>, Error> {
     }
 
     let content = fs::read_to_string(&path)?;
-    let todos: Vec<Todo> = serde_json::from_str(&content).map_err(|e| {
+    let mut todos: Vec<Todo> = serde_json::from_str(&content).map_err(|e| {
         Error::new(
             ErrorKind::InvalidData,
             format!("Could not parse todos: {}", e),
         )
     })?;
 
+    mark_blocked(&mut todos);
+
     Ok(todos)
 }
 
@@ -64,43 +71,54 @@ pub fn clear() -> Result<(), Error> {
 }
 
 // * Adds a new todo to the list, optionally at a specific index
-pub fn add(text: &str, index: Option<usize>) -> Result<(), Error> {
+// ? With a parent path (e.g. `todo add "step" --under 3`), the todo is added as a subtask
+pub fn add(new_todo: Todo, index: Option<usize>, under: Option<Vec<usize>>) -> Result<(), Error> {
     let mut todos = read()?;
 
-    // Create the new todo
-    let new_todo = Todo {
-        text: text.to_string(),
-        done: false,
+    // Add to the subtasks of the parent, or to the list itself
+    let siblings = match &under {
+        Some(parent) => {
+            validate_path(parent, &todos)?;
+            &mut find_mut(&mut todos, parent)
+                .expect("The parent path was validated")
+                .children
+        }
+        None => &mut todos,
     };
 
     // Either insert at a specific index or add to the end
     // TIP: `match index` is used to handle both cases
-    match index {
+    let position = match index {
         // If an index is provided, insert at that position
         Some(idx) => {
             // Check if the index is valid
-            validate_index_on_add(idx, &todos)?;
+            validate_index_on_add(idx, siblings)?;
 
             // Insert the new todo at the specified index
-            todos.insert(idx - 1, new_todo);
+            siblings.insert(idx - 1, new_todo);
+            idx
         }
         // If no index is provided, add to the end of the list
         None => {
             // Add to the end of the list (default behavior)
-            todos.push(new_todo);
+            siblings.push(new_todo);
+            siblings.len()
         }
-    }
+    };
 
     write(&todos)?;
 
     // Show the updated list with the new todo highlighted
-    view::todos::added(index)?;
+    let mut path = under.unwrap_or_default();
+    path.push(position);
+    view::todos::added(&path)?;
 
     Ok(())
 }
 
-// * Removes a todo from the list by index
-pub fn remove(index: usize) -> Result<(), Error> {
+// * Removes the selected todos from the list
+// ? All indices refer to the list as it was before the removal
+pub fn remove(selection: &Selection) -> Result<(), Error> {
     let mut todos = read()?;
 
     // Check if the todo list is empty first
@@ -110,19 +128,41 @@ pub fn remove(index: usize) -> Result<(), Error> {
         return Ok(());
     }
 
-    validate_index(index, &todos)?;
+    // Resolve and validate every selector before changing anything, so the update is atomic
+    let mut paths = resolve(selection, &todos)?;
+
+    // Subtasks go with their parent, so skip the ones whose parent is removed too
+    let selected = paths.clone();
+    paths.retain(|path| {
+        !selected
+            .iter()
+            .any(|parent| parent.len() < path.len() && path.starts_with(parent))
+    });
+
+    // Remove from the last to the first, so the earlier paths stay valid
+    let mut removed: Vec<(Vec<usize>, Todo)> = paths
+        .into_iter()
+        .rev()
+        .map(|path| {
+            let index = *path.last().expect("Paths are never empty");
+            let siblings = siblings_mut(&mut todos, &path).expect("The path was validated");
+            let todo = siblings.remove(index - 1);
+            (path, todo)
+        })
+        .collect();
+    removed.reverse();
 
-    let todo = todos.remove(index - 1);
     write(&todos)?;
 
-    // Show the updated list with the removed todo
-    view::todos::removed(index, &todo)?;
+    // Show the updated list with the removed todos
+    view::todos::removed(&removed)?;
 
     Ok(())
 }
 
-// * Toggles the done status of a todo by index
-pub fn toggle(index: usize) -> Result<(), Error> {
+// * Toggles the done status of the selected todos
+// ? With `cascade`, the subtasks of each todo get the same status as their parent
+pub fn toggle(selection: &Selection, cascade: bool) -> Result<(), Error> {
     let mut todos = read()?;
 
     // Check if the todo list is empty first
@@ -132,21 +172,52 @@ pub fn toggle(index: usize) -> Result<(), Error> {
         return Ok(());
     }
 
-    validate_index(index, &todos)?;
+    // Resolve and validate every selector before changing anything, so the update is atomic
+    let paths = resolve(selection, &todos)?;
 
     // Toggle the done status
-    todos[index - 1].done = !todos[index - 1].done;
+    let (created, unblocked) = utils::todos::toggle(&mut todos, &paths, cascade)?;
+
+    write(&todos)?;
+
+    // Show the updated list, the next occurrences and the todos that were waiting on the completed ones
+    view::todos::toggled(&paths)?;
+    view::todos::recurred(&todos, &created);
+    view::todos::unblocked(&todos, &unblocked);
+
+    Ok(())
+}
+
+// * Sets the metadata (tags, due date, priority) of the selected todos
+pub fn set(selection: &Selection, update: &TodoUpdate) -> Result<(), Error> {
+    let mut todos = read()?;
+
+    // Check if the todo list is empty first
+    if todos.is_empty() {
+        view::todos::title();
+        view::todos::empty();
+        return Ok(());
+    }
+
+    // Resolve and validate every selector before changing anything, so the update is atomic
+    let paths = resolve(selection, &todos)?;
+
+    for path in &paths {
+        find_mut(&mut todos, path)
+            .expect("The path was validated")
+            .apply(update);
+    }
 
     write(&todos)?;
 
     // Show the updated list
-    view::todos::toggled(index)?;
+    view::todos::updated(&paths)?;
 
     Ok(())
 }
 
-// * Replaces the text of a todo at a specific index
-pub fn replace(index: usize, new_text: &str) -> Result<(), Error> {
+// * Makes a todo wait on other todos (e.g. `todo block 3 --on 1`)
+pub fn block(path: &[usize], blockers: &[Vec<usize>]) -> Result<(), Error> {
     let mut todos = read()?;
 
     // Check if the todo list is empty first
@@ -156,33 +227,134 @@ pub fn replace(index: usize, new_text: &str) -> Result<(), Error> {
         return Ok(());
     }
 
-    validate_index(index, &todos)?;
+    // Validate every path and refuse cycles before changing anything
+    validate_path(path, &todos)?;
+    for blocker in blockers {
+        validate_path(blocker, &todos)?;
+
+        // NOTE: A todo without an id has nothing waiting on it, so it can't close a cycle
+        let todo_id = find(&todos, path).and_then(|todo| todo.id);
+        let blocker_todo = find(&todos, blocker).expect("The path was validated");
+        let cycle = todo_id.is_some_and(|id| depends_on(&todos, blocker_todo, id));
+
+        if blocker == path || cycle {
+            return Err(errors::dependency_cycle(
+                &format_path(path),
+                &format_path(blocker),
+            ));
+        }
+    }
 
-    // Save the old todo text before replacing
-    let old_text = todos[index - 1].text.clone();
+    for blocker in blockers {
+        let id = ensure_id(&mut todos, blocker).expect("The path was validated");
+        let todo = find_mut(&mut todos, path).expect("The path was validated");
+        if !todo.blocked_by.contains(&id) {
+            todo.blocked_by.push(id);
+        }
+    }
+
+    write(&todos)?;
+
+    // Show the updated list
+    view::todos::updated(&[path.to_vec()])?;
+
+    Ok(())
+}
+
+// * Stops a todo from waiting on some todos, or on all of them if none is given
+pub fn unblock(path: &[usize], blockers: &[Vec<usize>]) -> Result<(), Error> {
+    let mut todos = read()?;
+
+    // Check if the todo list is empty first
+    if todos.is_empty() {
+        view::todos::title();
+        view::todos::empty();
+        return Ok(());
+    }
+
+    validate_path(path, &todos)?;
+    for blocker in blockers {
+        validate_path(blocker, &todos)?;
+    }
 
-    // Replace the todo at the specified index
-    todos[index - 1].text = new_text.to_string();
+    let ids: Vec<u64> = blockers
+        .iter()
+        .filter_map(|blocker| find(&todos, blocker).and_then(|todo| todo.id))
+        .collect();
+
+    let todo = find_mut(&mut todos, path).expect("The path was validated");
+    if blockers.is_empty() {
+        todo.blocked_by.clear();
+    } else {
+        todo.blocked_by.retain(|id| !ids.contains(id));
+    }
 
     write(&todos)?;
 
-    // Show the updated list with the replaced todo, passing both old and new text
-    view::todos::replaced(index, &old_text, new_text)?;
+    // Show the updated list
+    view::todos::updated(&[path.to_vec()])?;
 
     Ok(())
 }
 
-// * Edits a todo at a specific index or allows interactive selection
-pub fn edit(index: Option<usize>) -> Result<(), Error> {
-    use crossterm::{
-        cursor::{Hide, MoveTo, Show},
-        event::{self, Event, KeyCode, KeyEventKind},
-        execute,
-        style::{Color, ResetColor, SetBackgroundColor, SetForegroundColor},
-        terminal::{Clear, ClearType, disable_raw_mode, enable_raw_mode},
-    };
-    use std::io::{Write, stdout};
+// * Starts the timer of a todo, stopping the one running on another todo
+// ? Only one timer runs at a time, so switching tasks is a single `todo start`
+pub fn start(path: &[usize]) -> Result<(), Error> {
+    let mut todos = read()?;
+
+    // Check if the todo list is empty first
+    if todos.is_empty() {
+        view::todos::title();
+        view::todos::empty();
+        return Ok(());
+    }
 
+    validate_path(path, &todos)?;
+
+    // Stop the timer running on another todo, if any
+    let stopped = find_running(&todos)
+        .filter(|running| running != path)
+        .map(|running| {
+            let todo = find_mut(&mut todos, &running).expect("The path was found");
+            let elapsed = todo.stop_timer().unwrap_or_default();
+            (running, todo.text.clone(), elapsed)
+        });
+
+    find_mut(&mut todos, path)
+        .expect("The path was validated")
+        .start_timer();
+
+    write(&todos)?;
+
+    // Show the updated list with the running todo
+    view::todos::updated(&[path.to_vec()])?;
+    if let Some((running, text, elapsed)) = stopped {
+        view::todos::stopped(&running, &text, elapsed);
+    }
+
+    Ok(())
+}
+
+// * Stops the running timer
+pub fn stop() -> Result<(), Error> {
+    let mut todos = read()?;
+
+    let path = find_running(&todos).ok_or_else(errors::no_timer)?;
+    let todo = find_mut(&mut todos, &path).expect("The path was found");
+    let elapsed = todo.stop_timer().unwrap_or_default();
+    let text = todo.text.clone();
+
+    write(&todos)?;
+
+    // Show the updated list and how long the timer ran
+    view::todos::updated(std::slice::from_ref(&path))?;
+    view::todos::stopped(&path, &text, elapsed);
+
+    Ok(())
+}
+
+// * Moves a todo to another position in the list
+pub fn move_todo(from: usize, destination: Destination) -> Result<(), Error> {
     let mut todos = read()?;
 
     // Check if the todo list is empty first
@@ -192,175 +364,109 @@ pub fn edit(index: Option<usize>) -> Result<(), Error> {
         return Ok(());
     }
 
-    // If no index was provided, start with the first todo selected
-    let mut selected_index = match index {
-        Some(idx) => {
-            validate_index(idx, &todos)?;
-            idx - 1 // Convert to 0-based index for internal use
+    validate_index(from, &todos)?;
+
+    // Find the new position, staying inside the list
+    let to = match destination {
+        Destination::Index(to) => {
+            validate_index(to, &todos)?;
+            to
         }
-        None => 0, // Start with the first todo selected
+        Destination::Up => from.saturating_sub(1).max(1),
+        Destination::Down => (from + 1).min(todos.len()),
+        Destination::Top => 1,
+        Destination::Bottom => todos.len(),
     };
 
-    // Enter raw mode for direct key handling
-    enable_raw_mode()?;
-
-    let mut stdout = stdout();
-
-    // Hide cursor during navigation
-    execute!(stdout, Hide)?;
-
-    // Interactive selection loop
-    'outer: loop {
-        // Clear screen and draw the todo list
-        execute!(stdout, Clear(ClearType::All), MoveTo(0, 0))?;
-
-        // Display title with exact positioning like in title()
-        execute!(stdout, MoveTo(0, 1))?; // Position at top with one blank line
-        execute!(stdout, MoveTo(0, 1))?; // Make sure we're at column 0
-        write!(stdout, "📝 {}", "Todo List ".bold())?;
-        write!(stdout, "{}", "Edit Mode".yellow().italic())?;
-        execute!(stdout, MoveTo(0, 2))?; // Next line, column 0
-        write!(stdout, "{}", "──────────────────────".dimmed())?;
-
-        execute!(stdout, MoveTo(0, 3))?; // Next line, column 0
-
-        // Display todos with the selected one highlighted - enforce positioning at column 0
-        for (i, todo) in todos.iter().enumerate() {
-            let index_str = format!("{}", i + 1);
-            let status = if todo.done {
-                "✓".green()
-            } else {
-                "○".yellow()
-            };
-
-            execute!(stdout, MoveTo(0, 3 + i as u16))?; // Position at start of line
-
-            // Highlight selected todo
-            if i == selected_index {
-                execute!(
-                    stdout,
-                    SetBackgroundColor(Color::Blue),
-                    SetForegroundColor(Color::White)
-                )?;
-                write!(stdout, "{} {} {}", index_str.purple(), status, todo.text)?;
-                execute!(stdout, ResetColor)?;
-            } else {
-                write!(stdout, "{} {} {}", index_str.purple(), status, todo.text)?;
-            }
-            writeln!(stdout)?; // End the line after writing the todo
-        }
+    let todo = todos.remove(from - 1);
+    todos.insert(to - 1, todo);
 
-        // Add navigation instructions at the bottom
-        execute!(stdout, MoveTo(0, 3 + todos.len() as u16 + 1))?; // Position after todos with a blank line
-        write!(
-            stdout,
-            "{}",
-            "Use ↑/↓ to navigate, Enter to edit, Esc to cancel"
-                .italic()
-                .dimmed()
-        )?;
-
-        stdout.flush()?;
-
-        // Handle key events
-        if let Event::Key(key) = event::read()? {
-            if key.kind == KeyEventKind::Press {
-                match key.code {
-                    KeyCode::Up => {
-                        if selected_index > 0 {
-                            selected_index -= 1;
-                        }
-                    }
-                    KeyCode::Down => {
-                        if selected_index < todos.len() - 1 {
-                            selected_index += 1;
-                        }
-                    }
-                    KeyCode::Enter => {
-                        // Exit raw mode before editing
-                        disable_raw_mode()?;
-                        execute!(stdout, Show)?;
-
-                        // Get current text
-                        let current_text = &todos[selected_index].text.clone();
-
-                        // Clear screen and prepare for text input
-                        execute!(stdout, Clear(ClearType::All), MoveTo(0, 0))?;
-
-                        // Use rustyline for input with pre-populated text
-                        let mut rl = DefaultEditor::new().map_err(|e| {
-                            Error::new(
-                                ErrorKind::Other,
-                                format!("Failed to initialize editor: {}", e),
-                            )
-                        })?;
-
-                        let prompt_text = format!(
-                            "{} {}{} ",
-                            "Edit todo".yellow(),
-                            (selected_index + 1).to_string().magenta(),
-                            ":".yellow()
-                        );
-
-                        // Pre-populate the input with the current todo text
-                        let new_text = rl
-                            .readline_with_initial(&prompt_text, (current_text, ""))
-                            .map_err(|e| {
-                            Error::new(ErrorKind::Other, format!("Failed to read input: {}", e))
-                        })?;
-
-                        let new_text = new_text.trim();
-
-                        // If the user just presses Enter without entering text, keep the original text
-                        if !new_text.is_empty() {
-                            // Save the old text before replacing
-                            let old_text = todos[selected_index].text.clone();
-
-                            // Replace the todo text
-                            todos[selected_index].text = new_text.to_string();
-
-                            // Write changes to file
-                            write(&todos)?;
-
-                            // Show the updated list with the replaced todo
-                            view::todos::replaced(selected_index + 1, &old_text, new_text)?;
-                        } else {
-                            println!("No changes made.");
-                        }
-
-                        // Exit the loop after editing
-                        break 'outer;
-                    }
-                    KeyCode::Esc => {
-                        // Cancel editing
-                        break 'outer;
-                    }
-                    _ => {}
-                }
-            }
-        }
+    write(&todos)?;
+
+    // Show the updated list with the moved todo
+    view::todos::moved(from, to)?;
+
+    Ok(())
+}
+
+// * Replaces the text of a todo at a specific index
+pub fn replace(path: &[usize], new_text: &str) -> Result<(), Error> {
+    let mut todos = read()?;
+
+    // Check if the todo list is empty first
+    if todos.is_empty() {
+        view::todos::title();
+        view::todos::empty();
+        return Ok(());
     }
 
-    // Ensure raw mode is disabled and cursor is shown before returning
-    disable_raw_mode()?;
-    execute!(stdout, Show)?;
+    validate_path(path, &todos)?;
+    let todo = find_mut(&mut todos, path).expect("The path was validated");
+
+    // Save the old todo text before replacing
+    let old_text = todo.text.clone();
+
+    // Replace the todo at the specified path
+    todo.text = new_text.to_string();
+
+    write(&todos)?;
+
+    // Show the updated list with the replaced todo, passing both old and new text
+    view::todos::replaced(path, &old_text, new_text)?;
 
     Ok(())
 }
 
-// 🔒 PRIVATE ---------------------------------
+// * Writes the notes of a todo, from stdin or in the user's editor
+pub fn note(path: &[usize]) -> Result<(), Error> {
+    let mut todos = read()?;
 
-// ? Creates the file path for the todo file
-fn file_path() -> PathBuf {
-    // Get the home directory
-    let mut path = dirs::home_dir().expect("Could not find home directory");
+    // Check if the todo list is empty first
+    if todos.is_empty() {
+        view::todos::title();
+        view::todos::empty();
+        return Ok(());
+    }
+
+    validate_path(path, &todos)?;
+    let todo = find_mut(&mut todos, path).expect("The path was validated");
+
+    let current = todo.notes.clone().unwrap_or_default();
+    let notes = notes::read_note(&current)?;
+
+    // NOTE: An empty note removes the notes of the todo
+    if notes.as_deref().unwrap_or_default() != current {
+        todo.notes = notes;
+        write(&todos)?;
+    }
+
+    // Show the todo with its notes
+    view::todos::show(path)
+}
 
-    // NOTE: Choose the directory where the todo file will be stored
-    path.push(".todo");
+// * Shows a todo with all its details and notes
+pub fn show(path: &[usize]) -> Result<(), Error> {
+    let todos = read()?;
 
-    // Create the directory if it doesn't exist
-    fs::create_dir_all(&path).expect("Could not create directory: .todo/");
+    // Check if the todo list is empty first
+    if todos.is_empty() {
+        view::todos::title();
+        view::todos::empty();
+        return Ok(());
+    }
+
+    validate_path(path, &todos)?;
+    view::todos::show(path)
+}
 
-    path.push("todos.json"); // Append the filename to the path
-    path // Return the full path to the todo file
+// * Edits a todo at a specific index or allows interactive selection
+pub fn edit(index: Option<usize>) -> Result<(), Error> {
+    editor::run(index, false)
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Creates the file path for the todo file
+fn file_path() -> PathBuf {
+    files::path("todos.json")
 }
@@ -1,3 +1,4 @@
+use crate::models::command::Command;
 use crate::models::todo::Todo;
 use colored::Colorize;
 use std::io::{Error, ErrorKind};
@@ -6,8 +7,15 @@ pub fn general(error: Error) {
     eprintln!("{} \n{}", "Error:".dimmed(), error);
 }
 
-pub fn invalid_number(index: &str) {
-    eprintln!("{} {}", "Invalid number:".red(), index.yellow())
+pub fn warning(error: Error) {
+    eprintln!("{} \n{}", "Warning:".dimmed(), error);
+}
+
+pub fn invalid_number(index: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!("{} {}", "Invalid number:".red(), index.yellow()),
+    )
 }
 
 pub fn invalid_number_with_length(index: usize, todos: &[Todo]) -> Error {
@@ -23,6 +31,20 @@ pub fn invalid_number_with_length(index: usize, todos: &[Todo]) -> Error {
     )
 }
 
+pub fn invalid_subtask(path: &str, parent: &str, count: usize) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} \nTodo {} has {} subtasks \n{}",
+            "Invalid number:".red(),
+            path.yellow(),
+            parent,
+            count.to_string().cyan(),
+            format!("Run {} to see the list", "`todo`".bold().green()).dimmed()
+        ),
+    )
+}
+
 pub fn invalid_number_zero() -> Error {
     Error::new(
         ErrorKind::InvalidInput,
@@ -34,3 +56,323 @@ pub fn invalid_number_zero() -> Error {
         ),
     )
 }
+
+pub fn unknown_command(input: &str, suggestion: Option<String>) -> Error {
+    let hint = match suggestion {
+        Some(name) => format!(
+            "Did you mean {}?",
+            format!("`todo {}`", name).bold().green()
+        ),
+        None => format!("Run {} to see all commands", "`todo help`".bold().green()),
+    };
+
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} \n{}",
+            "Invalid command:".red(),
+            input.yellow(),
+            hint.dimmed()
+        ),
+    )
+}
+
+pub fn unknown_flag(command: &Command, flag: &str, suggestion: Option<String>) -> Error {
+    let hint = match suggestion {
+        Some(name) => format!("Did you mean {}?", name.bold().green()),
+        None => help_hint(command),
+    };
+
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} \n{}",
+            "Invalid flag:".red(),
+            flag.yellow(),
+            hint.dimmed()
+        ),
+    )
+}
+
+pub fn missing_flag_value(flag: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {}",
+            "Missing value for flag:".red(),
+            format!("--{}", flag).yellow()
+        ),
+    )
+}
+
+pub fn wrong_arguments(command: &Command) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} \n{}",
+            "Wrong arguments for".red(),
+            format!("`{}`", command.usage_name()).yellow(),
+            help_hint(command).dimmed()
+        ),
+    )
+}
+
+pub fn invalid_pattern(pattern: &str, error: regex::Error) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} \n{}",
+            "Invalid pattern:".red(),
+            pattern.yellow(),
+            error
+        ),
+    )
+}
+
+pub fn no_match(selector: &str) -> Error {
+    Error::new(
+        ErrorKind::NotFound,
+        format!(
+            "{} {} \n{}",
+            "No todo matches".red(),
+            selector.yellow(),
+            format!("Run {} to see the list", "`todo`".bold().green()).dimmed()
+        ),
+    )
+}
+
+pub fn ambiguous_selector(count: usize) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} todos match \n{}",
+            "Ambiguous selector:".red(),
+            count.to_string().yellow(),
+            format!(
+                "Use a more specific pattern, or {} to select all of them",
+                "--all".bold().green()
+            )
+            .dimmed()
+        ),
+    )
+}
+
+pub fn cancelled() -> Error {
+    Error::new(ErrorKind::Interrupted, "Cancelled, no changes made")
+}
+
+pub fn invalid_date(date: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} \n{}",
+            "Invalid date:".red(),
+            date.yellow(),
+            "Use YYYY-MM-DD, today, tomorrow, a weekday (e.g. fri) or an offset (e.g. +3d, +2w)"
+                .dimmed()
+        ),
+    )
+}
+
+pub fn invalid_recurrence(rule: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} \n{}",
+            "Invalid repeat rule:".red(),
+            rule.yellow(),
+            "Use daily, weekly, monthly, weekdays (e.g. mon,fri), an interval (e.g. 3d, 2w) or after:3d"
+                .dimmed()
+        ),
+    )
+}
+
+pub fn invalid_priority(priority: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} \n{}",
+            "Invalid priority:".red(),
+            priority.yellow(),
+            "Use high, medium or low".dimmed()
+        ),
+    )
+}
+
+pub fn invalid_estimate(estimate: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} \n{}",
+            "Invalid estimate:".red(),
+            estimate.yellow(),
+            "Use a time (e.g. 30m, 2h, 1h30m) or story points (e.g. 3pt), up to 1000h or 1000pt"
+                .dimmed()
+        ),
+    )
+}
+
+pub fn invalid_month(month: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} \n{}",
+            "Invalid month:".red(),
+            month.yellow(),
+            "Use YYYY-MM, a month name (e.g. nov) or a number from 1 to 12".dimmed()
+        ),
+    )
+}
+
+pub fn invalid_chart(chart: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} \n{}",
+            "Invalid chart:".red(),
+            chart.yellow(),
+            "Use burndown or activity".dimmed()
+        ),
+    )
+}
+
+pub fn invalid_choice(flag: &str, value: &str, choices: &[&str]) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} \n{}",
+            format!("Invalid value for --{}:", flag).red(),
+            value.yellow(),
+            format!("Use one of: {}", choices.join(", ")).dimmed()
+        ),
+    )
+}
+
+pub fn invalid_keymap(reason: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!(
+            "{} {} \n{}",
+            "Invalid keymap:".red(),
+            reason.yellow(),
+            "Check ~/.todo/keymap.json (e.g. { \"toggle\": [\"<Space>\"], \"home\": [\"gg\"] })"
+                .dimmed()
+        ),
+    )
+}
+
+pub fn invalid_board(reason: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!(
+            "{} {} \n{}",
+            "Invalid board:".red(),
+            reason.yellow(),
+            "Check ~/.todo/board.json (e.g. [\"todo\", \"doing\", \"review\", \"done\"])".dimmed()
+        ),
+    )
+}
+
+pub fn invalid_theme(reason: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!(
+            "{} {} \n{}",
+            "Invalid theme:".red(),
+            reason.yellow(),
+            "Check ~/.todo/theme.json (e.g. { \"base\": \"light\", \"index\": \"bold blue\" })"
+                .dimmed()
+        ),
+    )
+}
+
+pub fn dependency_cycle(todo: &str, blocker: &str) -> Error {
+    let reason = if todo == blocker {
+        "A todo can't wait on itself".to_string()
+    } else {
+        format!(
+            "{} already waits on {}, directly or through other todos",
+            blocker, todo
+        )
+    };
+
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} {} {} \n{}",
+            "Todo".red(),
+            todo.yellow(),
+            "can't wait on".red(),
+            blocker.yellow(),
+            reason.dimmed()
+        ),
+    )
+}
+
+pub fn missing_flag(command: &Command, flag: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} \n{}",
+            "Missing flag:".red(),
+            format!("--{}", flag).yellow(),
+            help_hint(command).dimmed()
+        ),
+    )
+}
+
+pub fn nothing_to_update(command: &Command) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} \n{}",
+            "Nothing to update".red(),
+            help_hint(command).dimmed()
+        ),
+    )
+}
+
+pub fn no_next_occurrence(rule: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} {} \n{}",
+            "No next occurrence for the repeat rule:".red(),
+            rule.yellow(),
+            "It would fall past the last supported date, change the rule with `todo edit`".dimmed()
+        ),
+    )
+}
+
+pub fn editor_failed(editor: &str, reason: &str) -> Error {
+    Error::other(format!(
+        "{} {} \n{} \n{}",
+        "Could not run the editor:".red(),
+        editor.yellow(),
+        reason,
+        "Set $EDITOR to your editor, or pipe the note (e.g. `cat notes.md | todo note 1`)".dimmed()
+    ))
+}
+
+pub fn no_timer() -> Error {
+    Error::new(
+        ErrorKind::InvalidInput,
+        format!(
+            "{} \n{}",
+            "No timer is running".red(),
+            format!(
+                "Run {} to start one",
+                "`todo start <number>`".bold().green()
+            )
+            .dimmed()
+        ),
+    )
+}
+
+// ? Hint pointing to the help of a command
+fn help_hint(command: &Command) -> String {
+    format!(
+        "Run {} to see the usage",
+        format!("`{} --help`", command.usage_name()).bold().green()
+    )
+}
@@ -0,0 +1,41 @@
+use crate::models::todo::Todo;
+
+// * Columns of the kanban board, from the first step to done (e.g. `todo board`)
+// ? Open todos without a status are in the first column and done todos in the last one,
+// ? so `done` stays true exactly for the todos of the last column
+pub struct Board {
+    pub columns: Vec<String>,
+}
+
+impl Board {
+    // * Returns the 0-based index of the column a todo is in
+    // ? A status that is no longer a middle column (e.g. renamed in the config) falls back to the first
+    pub fn column_of(&self, todo: &Todo) -> usize {
+        if todo.done {
+            return self.last();
+        }
+
+        todo.status
+            .as_ref()
+            .and_then(|status| self.columns.iter().position(|column| column == status))
+            .filter(|&column| column < self.last())
+            .unwrap_or(0)
+    }
+
+    // * Moves a todo to a column, marking it as done only in the last one
+    pub fn move_to(&self, todo: &mut Todo, column: usize) {
+        let column = column.min(self.last());
+
+        todo.set_done(column == self.last());
+        todo.status = (column > 0 && column < self.last()).then(|| self.columns[column].clone());
+
+        if todo.done {
+            todo.stop_timer();
+        }
+    }
+
+    // * Returns the index of the last column, the done one
+    pub fn last(&self) -> usize {
+        self.columns.len().saturating_sub(1)
+    }
+}
@@ -0,0 +1,131 @@
+use crate::utils::todos::parse_path;
+
+// * Kind of value a positional argument accepts
+#[derive(Clone, Copy, PartialEq)]
+pub enum ArgKind {
+    Text,    // Free text (e.g. `"Buy milk"`)
+    Number,  // A 1-based todo number (e.g. `2`)
+    Path,    // A todo number or a dotted subtask path (e.g. `2` or `3.2`)
+    Numbers, // One or more todo numbers or ranges (e.g. `1 3 5-8`), always the last argument
+    Terms,   // One or more words of free text (e.g. `milk bread`), always the last argument
+    Value,   // A value the command parses itself (e.g. `1h30m`), shown with the numbers in the help
+}
+
+impl ArgKind {
+    // * Returns true if the argument can be repeated
+    pub fn is_variadic(&self) -> bool {
+        matches!(self, ArgKind::Numbers | ArgKind::Terms)
+    }
+
+    // * Returns true if the argument is free text
+    pub fn is_text(&self) -> bool {
+        matches!(self, ArgKind::Text | ArgKind::Terms)
+    }
+}
+
+// * A positional argument accepted by a command
+pub struct Arg {
+    pub name: &'static str, // ? Placeholder shown in the help (e.g. `<number>`)
+    pub kind: ArgKind,
+}
+
+// * A flag accepted by a command (e.g. `--help`)
+pub struct Flag {
+    pub long: &'static str,
+    pub short: Option<char>,
+    pub value: Option<&'static str>, // ? Placeholder of the flag value, if it takes one
+    pub description: &'static str,
+}
+
+// * One accepted form of a command, shown as its own line in the help
+// ? `args` is how many of the command's positional arguments this form takes
+// ? If the last one is variadic (see `ArgKind::is_variadic`), it can be repeated
+pub struct Form {
+    pub args: usize,
+    pub description: &'static str,
+}
+
+// * A command definition that drives parsing, help and suggestions
+pub struct Command {
+    pub name: &'static str, // ? Empty for the bare `todo` command
+    pub aliases: &'static [&'static str],
+    pub args: &'static [Arg],
+    pub forms: &'static [Form],
+    pub flags: &'static [Flag],
+}
+
+impl Command {
+    // * Returns true if the given number of positional arguments is accepted
+    pub fn accepts(&self, count: usize) -> bool {
+        self.forms.iter().any(|form| {
+            let variadic = form.args > 0 && self.args[form.args - 1].kind.is_variadic();
+            form.args == count || (variadic && count > form.args)
+        })
+    }
+
+    // * Returns true if the given word is the command name or one of its aliases
+    pub fn matches(&self, word: &str) -> bool {
+        !word.is_empty() && (self.name == word || self.aliases.contains(&word))
+    }
+
+    // * Returns the full command as typed in the terminal (e.g. `todo add`)
+    pub fn usage_name(&self) -> String {
+        if self.name.is_empty() {
+            "todo".to_string()
+        } else {
+            format!("todo {}", self.name)
+        }
+    }
+}
+
+// * The result of parsing the command line arguments
+pub struct Parsed {
+    pub command: &'static Command,
+    pub args: Vec<String>,
+    pub flags: Vec<(&'static str, Option<String>)>,
+    pub help: bool, // ? True if `--help` was passed to the command
+}
+
+impl Parsed {
+    // * Returns the positional argument at the given position
+    pub fn arg(&self, position: usize) -> Option<&str> {
+        self.args.get(position).map(String::as_str)
+    }
+
+    // * Returns the positional argument at the given position as a number
+    // ? Number arguments are already validated by the parser
+    pub fn number(&self, position: usize) -> Option<usize> {
+        self.arg(position).and_then(|arg| arg.parse().ok())
+    }
+
+    // * Returns the positional argument at the given position as a todo path (e.g. `[3, 2]`)
+    // ? Path arguments are already validated by the parser
+    pub fn path(&self, position: usize) -> Vec<usize> {
+        self.arg(position)
+            .and_then(|arg| parse_path(arg).ok())
+            .unwrap_or_default()
+    }
+
+    // * Returns true if the given flag was passed
+    pub fn has_flag(&self, long: &str) -> bool {
+        self.flags.iter().any(|(name, _)| *name == long)
+    }
+
+    // * Returns every value of a flag that can be passed several times (e.g. `--tag a --tag b`)
+    pub fn flag_values(&self, long: &str) -> Vec<&str> {
+        self.flags
+            .iter()
+            .filter(|(name, _)| *name == long)
+            .filter_map(|(_, value)| value.as_deref())
+            .collect()
+    }
+
+    // * Returns the value of the given flag, if it was passed
+    pub fn flag_value(&self, long: &str) -> Option<&str> {
+        self.flags
+            .iter()
+            .rev()
+            .find(|(name, _)| *name == long)
+            .and_then(|(_, value)| value.as_deref())
+    }
+}
@@ -0,0 +1,464 @@
+use std::collections::BTreeSet;
+use std::io::Error;
+
+use serde::Deserialize;
+
+use crate::models::board::Board;
+use crate::models::keymap::Keymap;
+use crate::models::todo::{Todo, TodoUpdate};
+use crate::utils;
+use crate::utils::fuzzy::fuzzy_match;
+use crate::utils::todos::Toggled;
+
+// * Actions available in the interactive editor
+// ? The kebab-case names (e.g. `move-up`) are used in the keymap file
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Action {
+    Up,          // Select the previous todo
+    Down,        // Select the next todo
+    PageUp,      // Select the todo one page up
+    PageDown,    // Select the todo one page down
+    Home,        // Select the first todo
+    End,         // Select the last todo
+    MoveUp,      // Move the selected todo one position up
+    MoveDown,    // Move the selected todo one position down
+    Grab,        // Grab the selected todo to move it with the selection keys, or drop it
+    Mark,        // Mark or unmark the selected todo for a batch action
+    Visual,      // Start or stop marking the range between the selection and where it started
+    Tag,         // Add a tag to the marked todos (or the selected one)
+    Toggle,      // Toggle the selected todo done/undone
+    ColumnLeft,  // Move the selected todo to the previous board column
+    ColumnRight, // Move the selected todo to the next board column
+    Delete,      // Delete the selected todo
+    Add,         // Add a new todo below the selected one
+    Edit,        // Edit the text of the selected todo
+    Undo,        // Undo the last change
+    Search,      // Filter the todos with a fuzzy search while typing
+    NextMatch,   // Select the next todo matching the last search
+    PrevMatch,   // Select the previous todo matching the last search
+    Quit,        // Leave the editor
+}
+
+// * State of the interactive editor (`todo edit` and `todo ui`)
+pub struct Editor {
+    pub todos: Vec<Todo>,
+    pub selected: usize,         // ? 0-based index of the selected todo
+    pub scroll: usize,           // ? 0-based index of the first visible todo
+    pub status: Option<String>,  // ? Message shown in the status bar
+    pub history: Vec<Vec<Todo>>, // ? Snapshots of the list before each change, for undo
+    pub keymap: Keymap,
+    pub board: Board, // ? Columns the todos move between with the left/right keys
+    pub pending: Vec<String>, // ? Keys typed so far of a multi-key binding (e.g. `g` of `gg`)
+    pub grabbed: Option<(usize, Vec<Todo>)>, // ? Position and list before the grabbed todo moved
+    pub marked: BTreeSet<usize>, // ? 0-based indexes of the todos marked for a batch action
+    pub visual: Option<usize>, // ? 0-based index where the visual range started
+    pub search: Option<Search>, // ? The current (or last) search, for `n`/`N` and highlighting
+}
+
+// * State of the incremental search (`/` in the editor)
+pub struct Search {
+    pub query: String,
+    pub typing: bool,  // ? The prompt is open and only the matching todos are shown
+    pub origin: usize, // ? Selection before the search, restored if it is cancelled
+}
+
+impl Editor {
+    // * Creates an editor with the given todo selected (0-based)
+    pub fn new(todos: Vec<Todo>, selected: usize) -> Self {
+        Editor {
+            todos,
+            selected,
+            scroll: 0,
+            status: None,
+            history: Vec::new(),
+            keymap: Keymap::default(),
+            board: Board::default(),
+            pending: Vec::new(),
+            grabbed: None,
+            marked: BTreeSet::new(),
+            visual: None,
+            search: None,
+        }
+    }
+
+    // * Selects the previous todo
+    pub fn up(&mut self) {
+        self.selected = self.selected.saturating_sub(1);
+    }
+
+    // * Selects the next todo
+    pub fn down(&mut self) {
+        if self.selected + 1 < self.todos.len() {
+            self.selected += 1;
+        }
+    }
+
+    // * Selects the todo one page (the given number of rows) up
+    pub fn page_up(&mut self, rows: usize) {
+        self.selected = self.selected.saturating_sub(rows.max(1));
+    }
+
+    // * Selects the todo one page (the given number of rows) down
+    pub fn page_down(&mut self, rows: usize) {
+        self.selected = (self.selected + rows.max(1)).min(self.todos.len().saturating_sub(1));
+    }
+
+    // * Selects the first todo
+    pub fn home(&mut self) {
+        self.selected = 0;
+    }
+
+    // * Selects the last todo
+    pub fn end(&mut self) {
+        self.selected = self.todos.len().saturating_sub(1);
+    }
+
+    // * Scrolls as little as possible so the selected todo is among the visible rows
+    pub fn scroll_into_view(&mut self, rows: usize) {
+        let rows = rows.max(1);
+
+        if self.selected < self.scroll {
+            self.scroll = self.selected;
+        } else if self.selected >= self.scroll + rows {
+            self.scroll = self.selected + 1 - rows;
+        }
+
+        // Don't leave empty rows at the bottom when the list shrinks
+        self.scroll = self.scroll.min(self.todos.len().saturating_sub(rows));
+    }
+
+    // * Opens the search prompt with an empty query
+    pub fn start_search(&mut self) {
+        self.search = Some(Search {
+            query: String::new(),
+            typing: true,
+            origin: self.selected,
+        });
+    }
+
+    // * Returns the 0-based indexes of the todos matching the search, with the matched characters
+    // ? Without a search (or with an empty query) every todo matches
+    pub fn matches(&self) -> Vec<(usize, Vec<usize>)> {
+        let query = self
+            .search
+            .as_ref()
+            .map_or("", |search| search.query.as_str());
+
+        self.todos
+            .iter()
+            .enumerate()
+            .filter_map(|(i, todo)| {
+                fuzzy_match(query, &todo.text).map(|(_, positions)| (i, positions))
+            })
+            .collect()
+    }
+
+    // * Changes the search query and selects the best match
+    pub fn set_query(&mut self, query: String) {
+        // Ties go to the first todo in the list
+        let best = self
+            .todos
+            .iter()
+            .enumerate()
+            .filter_map(|(i, todo)| fuzzy_match(&query, &todo.text).map(|(score, _)| (score, i)))
+            .max_by_key(|&(score, i)| (score, -(i as i64)));
+
+        if let Some(search) = self.search.as_mut() {
+            search.query = query;
+        }
+        if let Some((_, i)) = best {
+            self.selected = i;
+        }
+    }
+
+    // * Selects the next (or previous) todo matching the search, wrapping around
+    // ? Returns false if no todo matches
+    pub fn next_match(&mut self, forward: bool) -> bool {
+        let matches: Vec<usize> = self.matches().into_iter().map(|(i, _)| i).collect();
+
+        let found = if forward {
+            matches
+                .iter()
+                .find(|&&i| i > self.selected)
+                .or(matches.first())
+        } else {
+            matches
+                .iter()
+                .rev()
+                .find(|&&i| i < self.selected)
+                .or(matches.last())
+        };
+
+        match found {
+            Some(&i) => {
+                self.selected = i;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // * Closes the search prompt, keeping the selected match and the query for `n`/`N`
+    // ? Returns false (and restores the selection) if nothing matches
+    pub fn confirm_search(&mut self) -> bool {
+        if self.matches().is_empty() {
+            self.cancel_search();
+            return false;
+        }
+
+        if let Some(search) = self.search.as_mut() {
+            search.typing = false;
+        }
+        true
+    }
+
+    // * Closes the search, putting the selection back if the prompt was still open
+    pub fn cancel_search(&mut self) {
+        if let Some(search) = self.search.take()
+            && search.typing
+        {
+            self.selected = search.origin;
+        }
+    }
+
+    // * Returns true if the search prompt is open
+    pub fn is_searching(&self) -> bool {
+        self.search.as_ref().is_some_and(|search| search.typing)
+    }
+
+    // * Saves a snapshot of the list, so the next change can be undone
+    pub fn snapshot(&mut self) {
+        self.history.push(self.todos.clone());
+    }
+
+    // * Restores the list as it was before the last change
+    // ? Returns false if there is nothing to undo
+    pub fn undo(&mut self) -> bool {
+        match self.history.pop() {
+            Some(todos) => {
+                self.todos = todos;
+                self.clear_selection();
+                self.clamp_selection();
+                true
+            }
+            None => false,
+        }
+    }
+
+    // * Marks or unmarks the selected todo
+    pub fn toggle_mark(&mut self) {
+        if self.todos.is_empty() {
+            return;
+        }
+
+        if !self.marked.remove(&self.selected) {
+            self.marked.insert(self.selected);
+        }
+    }
+
+    // * Starts a visual range at the selected todo, or keeps its todos marked and stops it
+    pub fn toggle_visual(&mut self) {
+        if self.visual.is_some() {
+            self.marked.extend(self.targets());
+            self.visual = None;
+        } else if !self.todos.is_empty() {
+            self.visual = Some(self.selected);
+        }
+    }
+
+    // * Returns true if todos are marked or a visual range is active
+    pub fn has_selection(&self) -> bool {
+        !self.marked.is_empty() || self.visual.is_some()
+    }
+
+    // * Unmarks all todos and stops the visual range
+    pub fn clear_selection(&mut self) {
+        self.marked.clear();
+        self.visual = None;
+    }
+
+    // * Returns the sorted 0-based indexes a batch action applies to
+    // ? The marked todos plus the visual range, or just the selected todo if none
+    pub fn targets(&self) -> Vec<usize> {
+        let mut targets = self.marked.clone();
+
+        if let Some(anchor) = self.visual {
+            targets.extend(anchor.min(self.selected)..=anchor.max(self.selected));
+        }
+        if targets.is_empty() && !self.todos.is_empty() {
+            targets.insert(self.selected);
+        }
+
+        targets
+            .into_iter()
+            .filter(|&i| i < self.todos.len())
+            .collect()
+    }
+
+    // * Toggles the target todos the way `todo done` does (timers, next occurrences and blockers)
+    pub fn toggle(&mut self) -> Result<Toggled, Error> {
+        let paths: Vec<Vec<usize>> = self.targets().into_iter().map(|i| vec![i + 1]).collect();
+        utils::todos::toggle(&mut self.todos, &paths, false)
+    }
+
+    // * Moves the target todos one board column to the left or to the right
+    // ? Returns how many todos moved (the ones already in the first or last column stay)
+    pub fn move_column(&mut self, forward: bool) -> usize {
+        let mut moved = 0;
+
+        for i in self.targets() {
+            let column = self.board.column_of(&self.todos[i]);
+            let next = if forward {
+                (column + 1).min(self.board.last())
+            } else {
+                column.saturating_sub(1)
+            };
+
+            if next != column {
+                self.board.move_to(&mut self.todos[i], next);
+                moved += 1;
+            }
+        }
+
+        moved
+    }
+
+    // * Deletes the target todos and returns them
+    pub fn delete(&mut self) -> Vec<Todo> {
+        let targets = self.targets();
+
+        // Remove from the end so the remaining indexes stay valid
+        let mut deleted: Vec<Todo> = targets
+            .iter()
+            .rev()
+            .map(|&i| self.todos.remove(i))
+            .collect();
+        deleted.reverse();
+
+        // Select the todo now at the first deleted position
+        if let Some(&first) = targets.first() {
+            self.selected = first;
+        }
+        self.clear_selection();
+        self.clamp_selection();
+        deleted
+    }
+
+    // * Applies metadata changes (e.g. a new tag) to the target todos
+    // ? Returns how many todos were changed
+    pub fn update(&mut self, update: &TodoUpdate) -> usize {
+        let targets = self.targets();
+        for &i in &targets {
+            self.todos[i].apply(update);
+        }
+        targets.len()
+    }
+
+    // * Inserts a todo below the selected one and selects it
+    pub fn insert_below(&mut self, todo: Todo) {
+        let position = if self.todos.is_empty() {
+            0
+        } else {
+            self.selected + 1
+        };
+
+        // Marked positions would point at the wrong todos after the insert
+        self.clear_selection();
+        self.todos.insert(position, todo);
+        self.selected = position;
+    }
+
+    // * Moves the target todos by one position together, keeping them marked and selected
+    // ? Returns false if one of them is already at the edge of the list
+    pub fn move_targets(&mut self, up: bool) -> bool {
+        let targets = self.targets();
+        let at_edge = if up {
+            targets.first() == Some(&0)
+        } else {
+            targets.last() == Some(&self.todos.len().saturating_sub(1))
+        };
+
+        if targets.is_empty() || at_edge {
+            return false;
+        }
+
+        // Swap each todo with its neighbour, starting from the side it moves to
+        let shift = |i: usize| if up { i - 1 } else { i + 1 };
+        let order: Vec<usize> = if up {
+            targets.clone()
+        } else {
+            targets.iter().rev().copied().collect()
+        };
+        for i in order {
+            self.todos.swap(i, shift(i));
+        }
+
+        // The selection follows the todos only if it is one of them
+        if targets.contains(&self.selected) {
+            self.selected = shift(self.selected);
+        }
+        self.visual = self.visual.map(shift);
+        self.marked = self.marked.iter().map(|&i| shift(i)).collect();
+        true
+    }
+
+    // * Moves the selected todo by one position, keeping it selected
+    // ? Returns false if it is already at the edge of the list
+    pub fn move_selected(&mut self, up: bool) -> bool {
+        let target = if up {
+            self.selected.checked_sub(1)
+        } else {
+            Some(self.selected + 1).filter(|&target| target < self.todos.len())
+        };
+
+        match target {
+            Some(target) => {
+                self.todos.swap(self.selected, target);
+                self.selected = target;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // * Grabs the selected todo, so the selection keys move it until it is dropped
+    pub fn grab(&mut self) {
+        if !self.todos.is_empty() {
+            self.clear_selection();
+            self.grabbed = Some((self.selected, self.todos.clone()));
+        }
+    }
+
+    // * Drops the grabbed todo at its current position
+    // ? Returns the list as it was before the grab if the todo moved, to save it for undo
+    pub fn drop_grabbed(&mut self) -> Option<Vec<Todo>> {
+        let (origin, before) = self.grabbed.take()?;
+        (origin != self.selected).then_some(before)
+    }
+
+    // * Puts the grabbed todo back where it was
+    pub fn cancel_grab(&mut self) {
+        if let Some((origin, before)) = self.grabbed.take() {
+            self.todos = before;
+            self.selected = origin;
+        }
+    }
+
+    // * Moves the selected todo to a 0-based position, keeping it selected
+    pub fn move_selected_to(&mut self, target: usize) {
+        if self.todos.is_empty() {
+            return;
+        }
+
+        let target = target.min(self.todos.len() - 1);
+        let todo = self.todos.remove(self.selected);
+        self.todos.insert(target, todo);
+        self.selected = target;
+    }
+
+    // ? Keeps the selection inside the list after it shrinks
+    fn clamp_selection(&mut self) {
+        self.selected = self.selected.min(self.todos.len().saturating_sub(1));
+    }
+}
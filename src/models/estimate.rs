@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+
+use crate::utils::timer::format_minutes;
+
+// * How much effort a todo should take (e.g. `todo est 2 1h30m` or `todo est 2 3pt`)
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Estimate {
+    Minutes(u64),
+    Points(u64), // ? Story points, which don't convert to time
+}
+
+// * A sum of estimates, with minutes and points kept apart
+#[derive(Default, Clone, Copy)]
+pub struct Effort {
+    pub minutes: u64,
+    pub points: u64,
+}
+
+impl Estimate {
+    // * Returns the estimate as shown next to the todo (e.g. `1h 30m` or `3pt`)
+    pub fn describe(&self) -> String {
+        match self {
+            Estimate::Minutes(minutes) => format_minutes(*minutes),
+            Estimate::Points(points) => format!("{}pt", points),
+        }
+    }
+}
+
+impl Effort {
+    // * Adds an estimate to the sum
+    // ? Saturates instead of overflowing, for estimates edited by hand in the todo file
+    pub fn add(&mut self, estimate: Estimate) {
+        match estimate {
+            Estimate::Minutes(minutes) => self.minutes = self.minutes.saturating_add(minutes),
+            Estimate::Points(points) => self.points = self.points.saturating_add(points),
+        }
+    }
+
+    // * Returns true if nothing was added
+    pub fn is_zero(&self) -> bool {
+        self.minutes == 0 && self.points == 0
+    }
+
+    // * Returns the sum as shown in the footers (e.g. `3h 30m`, `5pt` or `3h 30m + 5pt`)
+    pub fn describe(&self) -> String {
+        match (self.minutes, self.points) {
+            (0, 0) => "0".to_string(),
+            (minutes, 0) => format_minutes(minutes),
+            (0, points) => format!("{}pt", points),
+            (minutes, points) => format!("{} + {}pt", format_minutes(minutes), points),
+        }
+    }
+}
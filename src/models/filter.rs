@@ -0,0 +1,40 @@
+use chrono::NaiveDate;
+
+use crate::models::todo::{Priority, Todo};
+
+// * Predicates used by `todo ls` to filter the list, combined with AND
+#[derive(Default)]
+pub struct Filter {
+    pub done: Option<bool>, // ? `Some(true)` for `--done`, `Some(false)` for `--open`
+    pub terms: Vec<String>, // ? Case-insensitive search terms, all must be found in the text
+    pub tags: Vec<String>,
+    pub due_before: Option<NaiveDate>,
+    pub created_after: Option<NaiveDate>,
+    pub priority: Option<Priority>,
+    pub ready: bool, // ? Only open todos that are not blocked (`--ready`)
+}
+
+impl Filter {
+    // * Returns true if the todo matches every predicate of the filter
+    pub fn matches(&self, todo: &Todo) -> bool {
+        let text = todo.text.to_lowercase();
+
+        self.done.is_none_or(|done| todo.done == done)
+            && self
+                .terms
+                .iter()
+                .all(|term| text.contains(&term.to_lowercase()))
+            && self.tags.iter().all(|tag| todo.tags.contains(tag))
+            && self
+                .due_before
+                .is_none_or(|date| todo.due.is_some_and(|due| due < date))
+            && self.created_after.is_none_or(|date| {
+                todo.created
+                    .is_some_and(|created| created.date_naive() > date)
+            })
+            && self
+                .priority
+                .is_none_or(|priority| todo.priority == Some(priority))
+            && (!self.ready || (!todo.done && !todo.blocked))
+    }
+}
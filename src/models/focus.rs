@@ -0,0 +1,102 @@
+use std::time::{Duration, Instant};
+
+// * The part of a pomodoro cycle the countdown is in
+#[derive(Clone, Copy, PartialEq)]
+pub enum Phase {
+    Work,
+    Break,
+}
+
+// * State of a pomodoro session (e.g. `todo focus 2 --minutes 25`)
+// ? The countdown alternates between work and break phases until the session is left
+pub struct Focus {
+    pub phase: Phase,
+    pub work: Duration, // ? Length of a work phase
+    pub rest: Duration, // ? Length of a break
+    pub pomodoros: u32, // ? Work phases completed during the session
+    pub breaks: u32,    // ? Breaks completed during the session
+    pub paused: bool,
+    elapsed: Duration, // ? Time counted in the phase before the last pause
+    resumed: Instant,  // ? When the countdown last started or resumed
+}
+
+impl Focus {
+    // * Creates a session starting with a work phase
+    pub fn new(work: Duration, rest: Duration) -> Self {
+        Focus {
+            phase: Phase::Work,
+            work,
+            rest,
+            pomodoros: 0,
+            breaks: 0,
+            paused: false,
+            elapsed: Duration::ZERO,
+            resumed: Instant::now(),
+        }
+    }
+
+    // * Returns the length of the current phase
+    pub fn length(&self) -> Duration {
+        match self.phase {
+            Phase::Work => self.work,
+            Phase::Break => self.rest,
+        }
+    }
+
+    // * Returns how long the current phase has been counting, without the pauses
+    pub fn elapsed(&self) -> Duration {
+        if self.paused {
+            self.elapsed
+        } else {
+            self.elapsed + self.resumed.elapsed()
+        }
+    }
+
+    // * Returns the time left in the current phase
+    pub fn remaining(&self) -> Duration {
+        self.length().saturating_sub(self.elapsed())
+    }
+
+    // * Returns true if the current phase has run out
+    pub fn is_over(&self) -> bool {
+        self.remaining().is_zero()
+    }
+
+    // * Returns true if the user is working right now (a work phase that isn't paused)
+    pub fn is_working(&self) -> bool {
+        self.phase == Phase::Work && !self.paused
+    }
+
+    // * Pauses or resumes the countdown
+    pub fn toggle_pause(&mut self) {
+        if self.paused {
+            self.resumed = Instant::now();
+        } else {
+            self.elapsed += self.resumed.elapsed();
+        }
+        self.paused = !self.paused;
+    }
+
+    // * Ends the current phase and starts the next one
+    // ? Only a phase that ran out counts, a skipped one doesn't
+    pub fn next_phase(&mut self, completed: bool) {
+        self.phase = match self.phase {
+            Phase::Work => {
+                if completed {
+                    self.pomodoros += 1;
+                }
+                Phase::Break
+            }
+            Phase::Break => {
+                if completed {
+                    self.breaks += 1;
+                }
+                Phase::Work
+            }
+        };
+
+        self.paused = false;
+        self.elapsed = Duration::ZERO;
+        self.resumed = Instant::now();
+    }
+}
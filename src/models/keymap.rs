@@ -0,0 +1,50 @@
+use crate::models::editor::Action;
+
+// * Key bindings of the interactive editor
+// ? Each binding is a sequence of keys in vim-like notation (e.g. `gg`, `dd`, `<S-Up>`)
+pub struct Keymap {
+    pub bindings: Vec<(Vec<String>, Action)>,
+}
+
+// * Result of looking up the keys typed so far
+#[derive(PartialEq, Debug)]
+pub enum Resolution {
+    Action(Action), // The keys are bound to an action
+    Pending,        // The keys start a longer binding (e.g. `g` of `gg`)
+    Unknown,        // No binding starts with the keys
+}
+
+impl Keymap {
+    // * Looks up the keys typed so far
+    pub fn resolve(&self, keys: &[String]) -> Resolution {
+        if let Some((_, action)) = self.bindings.iter().find(|(seq, _)| seq == keys) {
+            return Resolution::Action(*action);
+        }
+
+        if self.bindings.iter().any(|(seq, _)| seq.starts_with(keys)) {
+            return Resolution::Pending;
+        }
+
+        Resolution::Unknown
+    }
+
+    // * Returns the key sequences bound to an action, in notation form (e.g. `["<Up>", "k"]`)
+    pub fn keys_for(&self, action: Action) -> Vec<String> {
+        self.bindings
+            .iter()
+            .filter(|(_, bound)| *bound == action)
+            .map(|(seq, _)| {
+                // Wrap named keys back in angle brackets (e.g. `S-Up` -> `<S-Up>`)
+                seq.iter()
+                    .map(|key| {
+                        if key.chars().count() == 1 {
+                            key.clone()
+                        } else {
+                            format!("<{}>", key)
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
@@ -1,2 +1,14 @@
+pub mod board;
+pub mod command;
+pub mod editor;
+pub mod estimate;
+pub mod filter;
+pub mod focus;
 pub mod help;
+pub mod keymap;
+pub mod order;
+pub mod recurrence;
+pub mod selector;
+pub mod stats;
+pub mod theme;
 pub mod todo;
@@ -0,0 +1,50 @@
+// * How `todo ls` sorts the todos (display only, the stored order never changes)
+#[derive(Clone, Copy)]
+pub enum SortBy {
+    Created,  // Oldest first
+    Due,      // Earliest due date first
+    Priority, // Highest priority first
+    Alpha,    // Alphabetical, case-insensitive
+    Status,   // Open todos first
+}
+
+// * How `todo ls` splits the todos into sections
+#[derive(Clone, Copy)]
+pub enum GroupBy {
+    Status,
+    Tag,
+    DueBucket,
+    List, // ? All todos are kept in one list, so it is a single section
+}
+
+// * Buckets of due dates, in display order
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum DueBucket {
+    Overdue,
+    Today,
+    Tomorrow,
+    ThisWeek,
+    Later,
+    NoDate,
+}
+
+// * Sorting and grouping options of `todo ls`
+#[derive(Default)]
+pub struct Order {
+    pub sort: Option<SortBy>,
+    pub group: Option<GroupBy>,
+}
+
+impl DueBucket {
+    // * Returns the section title of the bucket
+    pub fn label(&self) -> &'static str {
+        match self {
+            DueBucket::Overdue => "Overdue",
+            DueBucket::Today => "Today",
+            DueBucket::Tomorrow => "Tomorrow",
+            DueBucket::ThisWeek => "This week",
+            DueBucket::Later => "Later",
+            DueBucket::NoDate => "No due date",
+        }
+    }
+}
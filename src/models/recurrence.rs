@@ -0,0 +1,31 @@
+use chrono::Weekday;
+use serde::{Deserialize, Serialize};
+
+// * How a todo repeats once it is done (e.g. `todo add "Standup" --repeat daily`)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum Recurrence {
+    Daily,
+    Weekly(Vec<Weekday>), // ? On the given weekdays, or a week after the due date if none
+    Monthly,
+    Every(u64), // ? Every N days after the due date
+    After(u64), // ? N days after the todo was completed
+}
+
+impl Recurrence {
+    // * Returns the rule as shown next to the todo (e.g. `weekly`, `mon, fri`, `every 3d`)
+    pub fn describe(&self) -> String {
+        match self {
+            Recurrence::Daily => "daily".to_string(),
+            Recurrence::Weekly(days) if days.is_empty() => "weekly".to_string(),
+            Recurrence::Weekly(days) => days
+                .iter()
+                .map(|day| day.to_string().to_lowercase())
+                .collect::<Vec<_>>()
+                .join(", "),
+            Recurrence::Monthly => "monthly".to_string(),
+            Recurrence::Every(days) => format!("every {}d", days),
+            Recurrence::After(days) => format!("{}d after done", days),
+        }
+    }
+}
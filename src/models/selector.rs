@@ -0,0 +1,49 @@
+use regex::Regex;
+
+use crate::utils::todos::format_path;
+
+// * A way of targeting todos from the command line
+pub enum Selector {
+    Index(usize),     // A 1-based todo number (e.g. `2`, or each number of `5-8`)
+    Path(Vec<usize>), // A dotted path to a subtask (e.g. `3.2`)
+    Text(String),     // A case-insensitive substring (e.g. `--match "standup"`)
+    Pattern(Regex),   // A regular expression (e.g. `/milk/` or `/milk/i`)
+    Fuzzy(String),    // A fuzzy match (e.g. `--fuzzy "stup"`)
+}
+
+// * The selectors passed to a command
+pub struct Selection {
+    pub selectors: Vec<Selector>,
+    pub all: bool, // ? True if every match of an ambiguous selector should be used
+}
+
+impl Selection {
+    // * Selects a single todo by number
+    pub fn index(index: usize) -> Self {
+        Selection {
+            selectors: vec![Selector::Index(index)],
+            all: false,
+        }
+    }
+
+    // * Selects a single todo or subtask by path
+    pub fn path(path: &[usize]) -> Self {
+        Selection {
+            selectors: vec![Selector::Path(path.to_vec())],
+            all: false,
+        }
+    }
+}
+
+impl Selector {
+    // * Returns the selector as typed by the user, for messages
+    pub fn describe(&self) -> String {
+        match self {
+            Selector::Index(index) => index.to_string(),
+            Selector::Path(path) => format_path(path),
+            Selector::Text(text) => format!("--match \"{}\"", text),
+            Selector::Pattern(regex) => format!("/{}/", regex.as_str()),
+            Selector::Fuzzy(text) => format!("--fuzzy \"{}\"", text),
+        }
+    }
+}
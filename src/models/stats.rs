@@ -0,0 +1,42 @@
+use chrono::NaiveDate;
+use serde::Serialize;
+
+// * Statistics about the todo list, shown by `todo stats` (or printed as JSON with `--json`)
+// ? Subtasks count as todos of their own
+#[derive(Serialize)]
+pub struct Stats {
+    pub total: usize,
+    pub open: usize,
+    pub done: usize,
+    pub completion_rate: f64, // ? Share of done todos, from 0 to 1
+    pub days: Vec<Period>,    // ? The last days, oldest first
+    pub weeks: Vec<Period>,   // ? The last weeks (starting on monday), oldest first
+    pub average_open_age_days: Option<f64>,
+    pub oldest_open: Option<OldestTodo>,
+    pub current_streak: usize, // ? Days in a row up to today (or yesterday) with a completed todo
+    pub longest_streak: usize,
+}
+
+// * A chart drawn by `todo chart`
+#[derive(Clone, Copy)]
+pub enum Chart {
+    Burndown, // ? Open todos at the end of each day
+    Activity, // ? Todos completed each day
+}
+
+// * How many todos were added and completed in a day or a week
+#[derive(Serialize)]
+pub struct Period {
+    pub start: NaiveDate,
+    pub added: usize,
+    pub completed: usize,
+}
+
+// * The open todo waiting the longest
+#[derive(Serialize)]
+pub struct OldestTodo {
+    pub number: String, // ? The number or subtask path (e.g. `3.2`)
+    pub text: String,
+    pub created: NaiveDate,
+    pub age_days: i64,
+}
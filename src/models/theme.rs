@@ -0,0 +1,87 @@
+use colored::{Color, ColoredString, Colorize};
+
+// * Colors and marks of the list, picked by role so they can change with the terminal background
+// ? Built-in themes are `dark` (the default), `light` and `high-contrast`, see `data::theme`
+pub struct Theme {
+    pub index: Style,     // ? Todo numbers (e.g. `3` or `3.2`)
+    pub open: Style,      // ? The mark of open todos
+    pub done: Style,      // ? The mark of done todos
+    pub added: Style,     // ? Newly added rows
+    pub removed: Style,   // ? Removed rows
+    pub toggled: Style, // ? Markers of changed rows (e.g. `✦` after `todo done`, `⇅` after `todo mv`)
+    pub title: Style,   // ? Titles and headings
+    pub hint: Style,    // ? Secondary text (dividers, dates, counts and hints)
+    pub command: Style, // ? Command names in the help
+    pub argument: Style, // ? Arguments and flag values in the help
+    pub high: Style,    // ? The `!!!` of high priority todos
+    pub medium: Style,  // ? The `!!` of medium priority todos
+    pub low: Style,     // ? The `!` of low priority todos
+    pub overdue: Style, // ? Due dates in the past, and their counts in the calendar
+    pub due: Style,     // ? Counts of todos due in the calendar
+    pub tag: Style,     // ? Tags (e.g. `#work`)
+    pub status: Style,  // ? Board columns (e.g. `▸ doing`) and the editor mode
+    pub timer: Style,   // ? Running timers (e.g. `⏱ 5m`) and the time the command took
+    pub highlight: Style, // ? Text matched by a search
+    pub selected: Style, // ? The selected row and the status bar of the editor
+    pub grabbed: Style, // ? The row of the editor being moved
+    pub open_mark: String,
+    pub done_mark: String,
+}
+
+// * How a role is shown: a color, a background and a few text attributes
+#[derive(Clone, Copy, Default)]
+pub struct Style {
+    pub color: Option<Color>,
+    pub background: Option<Color>,
+    pub bold: bool,
+    pub dimmed: bool,
+    pub italic: bool,
+    pub underline: bool,
+}
+
+impl Style {
+    // * Returns a style with a color only
+    pub fn color(color: Color) -> Self {
+        Style {
+            color: Some(color),
+            ..Style::default()
+        }
+    }
+
+    // * Returns the text in this style, on top of the styles it already has
+    pub fn paint(&self, text: impl Into<ColoredString>) -> ColoredString {
+        let mut painted: ColoredString = text.into();
+
+        if let Some(color) = self.color {
+            painted = painted.color(color);
+        }
+        if let Some(background) = self.background {
+            painted = painted.on_color(background);
+        }
+        if self.bold {
+            painted = painted.bold();
+        }
+        if self.dimmed {
+            painted = painted.dimmed();
+        }
+        if self.italic {
+            painted = painted.italic();
+        }
+        if self.underline {
+            painted = painted.underline();
+        }
+
+        painted
+    }
+}
+
+impl Theme {
+    // * Returns the mark and its style for the status of a todo (e.g. a blue `☐` or a green `✔︎`)
+    pub fn status(&self, done: bool) -> ColoredString {
+        if done {
+            self.done.paint(self.done_mark.as_str())
+        } else {
+            self.open.paint(self.open_mark.as_str())
+        }
+    }
+}
@@ -1,7 +1,269 @@
+use std::io::Error;
+use std::time::Duration;
+
+use chrono::{DateTime, Local, NaiveDate};
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug)]
+use crate::models::estimate::Estimate;
+use crate::models::recurrence::Recurrence;
+use crate::utils::dates::next_due;
+
+// TIP: Every optional field has a default, so todo files from older versions still load
+
+#[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct Todo {
     pub text: String,
     pub done: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>, // ? Board column between the first and the done one (e.g. `doing`)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due: Option<NaiveDate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<Priority>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub created: Option<DateTime<Local>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub completed: Option<DateTime<Local>>, // ? When the todo was marked as done, cleared when reopened
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub estimate: Option<Estimate>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>, // ? Multi-line details (e.g. links or steps to reproduce)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub children: Vec<Todo>, // ? Subtasks, addressed with dotted paths (e.g. `3.2`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<u64>, // ? Stable identity, given to a todo when another one depends on it
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub blocked_by: Vec<u64>, // ? Ids of the todos that must be done first
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub repeat: Option<Recurrence>, // ? Creates the next occurrence when the todo is done
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub intervals: Vec<Interval>, // ? Time worked on the todo, recorded with `todo start` and `todo stop`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub pomodoros: Vec<DateTime<Local>>, // ? When each pomodoro of `todo focus` was completed
+    #[serde(skip)]
+    pub blocked: bool, // ? True if one of the blockers is still open, computed when reading
+}
+
+// * A period of work on a todo, without an end while its timer is running
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Interval {
+    pub start: DateTime<Local>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<DateTime<Local>>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum Priority {
+    Low,
+    Medium,
+    High,
+}
+
+// * Changes to apply to the metadata of one or more todos (e.g. `todo set 2 --tag work`)
+// ? `Some(None)` clears a field, `None` leaves it untouched
+#[derive(Default)]
+pub struct TodoUpdate {
+    pub add_tags: Vec<String>,
+    pub remove_tags: Vec<String>,
+    pub due: Option<Option<NaiveDate>>,
+    pub priority: Option<Option<Priority>>,
+    pub repeat: Option<Option<Recurrence>>,
+    pub estimate: Option<Option<Estimate>>,
+}
+
+// * Where to move a todo (e.g. `todo mv 3 1`, `todo up 3`, `todo top 3`)
+#[derive(Clone, Copy)]
+pub enum Destination {
+    Index(usize), // A 1-based position
+    Up,
+    Down,
+    Top,
+    Bottom,
+}
+
+impl Todo {
+    // * Creates a new open todo, stamped with the current time
+    pub fn new(text: &str) -> Self {
+        Todo {
+            text: text.to_string(),
+            done: false,
+            status: None,
+            tags: Vec::new(),
+            due: None,
+            priority: None,
+            created: Some(Local::now()),
+            completed: None,
+            estimate: None,
+            notes: None,
+            children: Vec::new(),
+            id: None,
+            blocked_by: Vec::new(),
+            repeat: None,
+            intervals: Vec::new(),
+            pomodoros: Vec::new(),
+            blocked: false,
+        }
+    }
+
+    // * Applies metadata changes to the todo
+    pub fn apply(&mut self, update: &TodoUpdate) {
+        for tag in &update.add_tags {
+            if !self.tags.contains(tag) {
+                self.tags.push(tag.clone());
+            }
+        }
+        self.tags.retain(|tag| !update.remove_tags.contains(tag));
+
+        if let Some(due) = update.due {
+            self.due = due;
+        }
+        if let Some(priority) = update.priority {
+            self.priority = priority;
+        }
+        if let Some(repeat) = &update.repeat {
+            self.repeat = repeat.clone();
+        }
+        if let Some(estimate) = update.estimate {
+            self.estimate = estimate;
+        }
+    }
+
+    // * Returns how many subtasks are done and how many there are, if the todo has any
+    pub fn progress(&self) -> Option<(usize, usize)> {
+        if self.children.is_empty() {
+            return None;
+        }
+
+        let done = self.children.iter().filter(|child| child.done).count();
+        Some((done, self.children.len()))
+    }
+
+    // * Returns the interval of the running timer, if the todo has one
+    pub fn running(&self) -> Option<&Interval> {
+        self.intervals
+            .last()
+            .filter(|interval| interval.end.is_none())
+    }
+
+    // * Starts the timer of the todo, unless it is already running
+    pub fn start_timer(&mut self) {
+        if self.running().is_none() {
+            self.intervals.push(Interval {
+                start: Local::now(),
+                end: None,
+            });
+        }
+    }
+
+    // * Stops the running timer of the todo and returns how long it ran
+    pub fn stop_timer(&mut self) -> Option<Duration> {
+        let interval = self
+            .intervals
+            .last_mut()
+            .filter(|interval| interval.end.is_none())?;
+        interval.end = Some(Local::now());
+        Some(interval.within(None, None))
+    }
+
+    // * Returns the time worked on the todo between two instants (unbounded if `None`)
+    pub fn time_spent(
+        &self,
+        from: Option<DateTime<Local>>,
+        to: Option<DateTime<Local>>,
+    ) -> Duration {
+        self.intervals
+            .iter()
+            .map(|interval| interval.within(from, to))
+            .sum()
+    }
+
+    // * Returns how many pomodoros were completed between two instants (unbounded if `None`)
+    pub fn pomodoros_within(
+        &self,
+        from: Option<DateTime<Local>>,
+        to: Option<DateTime<Local>>,
+    ) -> usize {
+        self.pomodoros
+            .iter()
+            .filter(|&&completed| from.is_none_or(|from| completed >= from))
+            .filter(|&&completed| to.is_none_or(|to| completed < to))
+            .count()
+    }
+
+    // * Returns the next occurrence of a recurring todo completed on the given date
+    // ? The occurrence starts open (subtasks too), with the next due date, no dependents and no time worked
+    // ? Gives nothing for a todo that doesn't repeat, fails if the next due date is out of range
+    pub fn next_occurrence(&self, completed: NaiveDate) -> Result<Option<Todo>, Error> {
+        let Some(repeat) = self.repeat.as_ref() else {
+            return Ok(None);
+        };
+
+        let mut next = self.clone();
+        next.set_done_with_subtasks(false);
+        next.due = Some(next_due(repeat, self.due, completed)?);
+        next.created = Some(Local::now());
+        next.clear_tracking();
+        Ok(Some(next))
+    }
+
+    // * Sets the done status of the todo, stamping when it was completed
+    // ? A todo done or reopened leaves its board column, for the done or the first one
+    pub fn set_done(&mut self, done: bool) {
+        if done != self.done {
+            self.status = None;
+        }
+        if done && !self.done {
+            self.completed = Some(Local::now());
+        } else if !done {
+            self.completed = None;
+        }
+        self.done = done;
+    }
+
+    // * Sets the done status of the todo and all its subtasks
+    pub fn set_done_with_subtasks(&mut self, done: bool) {
+        self.set_done(done);
+        for child in &mut self.children {
+            child.set_done_with_subtasks(done);
+        }
+    }
+
+    // ? Forgets the id, the time worked and the pomodoros of the todo and its subtasks
+    // ? Without an id, the copy is a new todo: the ones waiting on the original don't wait on it
+    fn clear_tracking(&mut self) {
+        self.id = None;
+        self.intervals.clear();
+        self.pomodoros.clear();
+        for child in &mut self.children {
+            child.clear_tracking();
+        }
+    }
+}
+
+impl Interval {
+    // * Returns how much of the interval falls between two instants (unbounded if `None`)
+    // ? A running interval lasts until now
+    pub fn within(&self, from: Option<DateTime<Local>>, to: Option<DateTime<Local>>) -> Duration {
+        let end = self.end.unwrap_or_else(Local::now);
+        let end = to.map_or(end, |to| end.min(to));
+        let start = from.map_or(self.start, |from| self.start.max(from));
+
+        // NOTE: An interval outside the range gives a negative delta, which counts as zero
+        (end - start).to_std().unwrap_or_default()
+    }
+}
+
+impl TodoUpdate {
+    // * Returns true if the update doesn't change anything
+    pub fn is_empty(&self) -> bool {
+        self.add_tags.is_empty()
+            && self.remove_tags.is_empty()
+            && self.due.is_none()
+            && self.priority.is_none()
+            && self.repeat.is_none()
+            && self.estimate.is_none()
+    }
 }
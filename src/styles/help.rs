@@ -1,17 +1,19 @@
 use colored::{ColoredString, Colorize};
 
+use crate::data::theme;
+
 pub fn title(title: &str) -> ColoredString {
-    title.bold().green()
+    theme::current().title.paint(title)
 }
 
 pub fn command(command: &str) -> ColoredString {
-    command.bold().cyan()
+    theme::current().command.paint(command)
 }
 
 pub fn command_text(text: &str) -> ColoredString {
-    text.bold().yellow()
+    theme::current().argument.paint(text).bold()
 }
 
 pub fn command_arg(arg: &str) -> ColoredString {
-    arg.cyan()
+    theme::current().argument.paint(arg)
 }
@@ -0,0 +1,206 @@
+use std::io::Error;
+
+use crate::data::commands::{COMMANDS, GLOBAL_FLAGS, HELP_FLAG};
+use crate::errors;
+use crate::models::command::{ArgKind, Command, Flag, Parsed};
+use crate::utils::selectors;
+use crate::utils::todos::parse_path;
+
+// * Parses the command line arguments (without the program name)
+pub fn parse(args: &[String]) -> Result<Parsed, Error> {
+    // Use the bare `todo` command if no command name is given (e.g. `todo` or `todo --help`)
+    let (command, rest) = match args.first() {
+        Some(first) if !is_flag(first) => (find_command(first)?, &args[1..]),
+        _ => (bare_command(), args),
+    };
+
+    let mut parsed = Parsed {
+        command,
+        args: Vec::new(),
+        flags: Vec::new(),
+        help: false,
+    };
+
+    let mut tokens = rest.iter();
+    while let Some(token) = tokens.next() {
+        // TIP: `--` ends the flags, so text starting with a dash can still be passed
+        if token == "--" {
+            parsed.args.extend(tokens.by_ref().cloned());
+            break;
+        }
+
+        if !is_flag(token) {
+            parsed.args.push(token.clone());
+            continue;
+        }
+
+        // Split `--flag=value` into its name and inline value
+        let (name, inline_value) = match token.split_once('=') {
+            Some((name, value)) => (name, Some(value.to_string())),
+            None => (token.as_str(), None),
+        };
+
+        let flag = find_flag(command, name)?;
+
+        if flag.long == HELP_FLAG.long {
+            parsed.help = true;
+            continue;
+        }
+
+        let value = match flag.value {
+            Some(_) => match inline_value.or_else(|| tokens.next().cloned()) {
+                Some(value) => Some(value),
+                None => return Err(errors::missing_flag_value(flag.long)),
+            },
+            None => None,
+        };
+
+        parsed.flags.push((flag.long, value));
+    }
+
+    // The help of a command is always available, regardless of the other arguments
+    if parsed.help {
+        return Ok(parsed);
+    }
+
+    validate_args(&parsed)?;
+
+    Ok(parsed)
+}
+
+// * Finds a command by its name or one of its aliases
+pub fn find_command(word: &str) -> Result<&'static Command, Error> {
+    COMMANDS
+        .iter()
+        .find(|cmd| cmd.matches(word))
+        .ok_or_else(|| errors::unknown_command(word, suggest_command(word)))
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Returns the bare `todo` command
+fn bare_command() -> &'static Command {
+    COMMANDS
+        .iter()
+        .find(|cmd| cmd.name.is_empty())
+        .expect("The bare `todo` command must be defined")
+}
+
+// ? Returns true if the token looks like a flag (e.g. `--help` or `-h`)
+fn is_flag(token: &str) -> bool {
+    let mut chars = token.chars();
+
+    match (chars.next(), chars.next(), chars.next()) {
+        (Some('-'), Some('-'), Some(c)) => c.is_ascii_alphabetic(),
+        (Some('-'), Some(c), None) => c.is_ascii_alphabetic(),
+        _ => false,
+    }
+}
+
+// ? Finds a flag of the command by its long (`--name`) or short (`-n`) form
+fn find_flag(command: &'static Command, token: &str) -> Result<&'static Flag, Error> {
+    let flags = || command.flags.iter().chain(GLOBAL_FLAGS);
+
+    let found = match token.strip_prefix("--") {
+        Some(long) => flags().find(|flag| flag.long == long),
+        None => {
+            let short = token.chars().nth(1);
+            flags().find(|flag| flag.short.is_some() && flag.short == short)
+        }
+    };
+
+    match found {
+        Some(flag) => Ok(flag),
+        None => {
+            let names: Vec<&str> = flags().map(|flag| flag.long).collect();
+            let suggestion = token
+                .strip_prefix("--")
+                .and_then(|long| closest(long, &names))
+                .map(|long| format!("--{}", long));
+
+            Err(errors::unknown_flag(command, token, suggestion))
+        }
+    }
+}
+
+// ? Checks the number and kind of the positional arguments
+fn validate_args(parsed: &Parsed) -> Result<(), Error> {
+    let command = parsed.command;
+
+    if !command.accepts(parsed.args.len()) {
+        return Err(errors::wrong_arguments(command));
+    }
+
+    for (position, arg) in parsed.args.iter().enumerate() {
+        // TIP: Arguments past the last definition are repetitions of a variadic argument
+        let kind = command.args[position.min(command.args.len() - 1)].kind;
+
+        match kind {
+            ArgKind::Number if arg.parse::<usize>().is_err() => {
+                return Err(errors::invalid_number(arg));
+            }
+            ArgKind::Numbers => {
+                selectors::parse_arg(arg)?;
+            }
+            ArgKind::Path => {
+                parse_path(arg)?;
+            }
+            _ => {}
+        }
+    }
+
+    Ok(())
+}
+
+// ? Suggests the name of the closest command to a mistyped one
+fn suggest_command(word: &str) -> Option<String> {
+    let mut best: Option<(usize, &Command)> = None;
+
+    for cmd in COMMANDS.iter().filter(|cmd| !cmd.name.is_empty()) {
+        for name in std::iter::once(&cmd.name).chain(cmd.aliases) {
+            let distance = edit_distance(word, name);
+            if best.is_none_or(|(best_distance, _)| distance < best_distance) {
+                best = Some((distance, cmd));
+            }
+        }
+    }
+
+    best.filter(|(distance, _)| is_close(word, *distance))
+        .map(|(_, cmd)| cmd.name.to_string())
+}
+
+// ? Returns the closest candidate to the word, if it is close enough
+fn closest<'a>(word: &str, candidates: &[&'a str]) -> Option<&'a str> {
+    candidates
+        .iter()
+        .map(|candidate| (edit_distance(word, candidate), *candidate))
+        .min_by_key(|(distance, _)| *distance)
+        .filter(|(distance, _)| is_close(word, *distance))
+        .map(|(_, candidate)| candidate)
+}
+
+// ? Returns true if an edit distance is small enough to be a likely typo
+fn is_close(word: &str, distance: usize) -> bool {
+    distance <= (word.chars().count() / 3).max(1)
+}
+
+// ? Levenshtein distance between two words
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            let insertion = current[j] + 1;
+            let deletion = previous[j + 1] + 1;
+            current.push(substitution.min(insertion).min(deletion));
+        }
+
+        previous = current;
+    }
+
+    previous[b.len()]
+}
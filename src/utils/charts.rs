@@ -0,0 +1,96 @@
+use std::io::Error;
+
+use crate::errors;
+use crate::models::stats::Chart;
+use crate::utils::terminal::supports_unicode;
+
+// * Bars of a sparkline, from the lowest to the highest value
+// ? Also the top of the bars of a chart, in eighths of a row
+const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+// * Bars of a sparkline when the output can't show Unicode (see `utils::terminal::supports_unicode`)
+const ASCII_BARS: [char; 8] = ['_', '.', ',', ':', ';', '=', '*', '#'];
+
+// * Parses the name of a chart from the command line (e.g. `burndown`)
+pub fn parse_chart(input: &str) -> Result<Chart, Error> {
+    match input.trim().to_lowercase().as_str() {
+        "burndown" | "burn" | "b" => Ok(Chart::Burndown),
+        "activity" | "act" | "a" => Ok(Chart::Activity),
+        _ => Err(errors::invalid_chart(input)),
+    }
+}
+
+// * Draws values as a sparkline scaled to the highest one (e.g. `[0, 2, 8, 1]` -> `▁▃█▂`)
+// ? Any value above zero gets at least the second bar, so it stands out from an empty one
+pub fn sparkline(values: &[usize]) -> String {
+    let max = values.iter().copied().max().unwrap_or_default();
+    let bars = if supports_unicode() { BARS } else { ASCII_BARS };
+
+    values
+        .iter()
+        .map(|&value| {
+            let level = if value == 0 {
+                0
+            } else {
+                ((value * (BARS.len() - 1)).div_ceil(max)).max(1)
+            };
+            bars[level]
+        })
+        .collect()
+}
+
+// * Fits one value per day into the columns of a chart
+// ? With more days than columns, each column holds several days:
+// ? their sum for counts (e.g. completed todos), or the last one for levels (e.g. open todos)
+pub fn fit(values: &[usize], columns: usize, sum: bool) -> Vec<usize> {
+    if values.len() <= columns {
+        return values.to_vec();
+    }
+
+    (0..columns)
+        .map(|column| {
+            let bucket =
+                &values[column * values.len() / columns..(column + 1) * values.len() / columns];
+            if sum {
+                bucket.iter().sum()
+            } else {
+                bucket.last().copied().unwrap_or_default()
+            }
+        })
+        .collect()
+}
+
+// * Draws values as vertical bars scaled to the highest one, and returns the rows from top to bottom
+// ? The top of each bar is drawn in eighths of a row, or with `.` for half rows in ASCII
+pub fn bars(
+    values: &[usize],
+    height: usize,
+    bar_width: usize,
+    gap: bool,
+    unicode: bool,
+) -> Vec<String> {
+    let max = values.iter().copied().max().unwrap_or_default().max(1);
+
+    (0..height)
+        .rev()
+        .map(|row| {
+            values
+                .iter()
+                .map(|&value| {
+                    // NOTE: Rounding up keeps the smallest values visible
+                    let eighths = (value * height * 8).div_ceil(max);
+                    let level = eighths.saturating_sub(row * 8).min(8);
+                    let glyph = match (level, unicode) {
+                        (0, _) => ' ',
+                        (level, true) => BARS[level - 1],
+                        (8, false) => '#',
+                        (_, false) => '.',
+                    };
+
+                    let bar = glyph.to_string().repeat(bar_width);
+                    if gap { format!("{} ", bar) } else { bar }
+                })
+                .collect()
+        })
+        .collect()
+}
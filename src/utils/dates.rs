@@ -0,0 +1,219 @@
+use std::io::Error;
+
+use chrono::{DateTime, Datelike, Days, Local, Month, Months, NaiveDate, TimeZone, Weekday};
+
+use crate::errors;
+use crate::models::order::DueBucket;
+use crate::models::recurrence::Recurrence;
+
+// * Returns today's date in the local timezone
+pub fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+// * Parses a date from the command line
+// ? Accepts `2026-10-20`, `today`, `tomorrow`, a weekday (e.g. `fri`) or an offset (e.g. `+3d`, `+2w`)
+pub fn parse_date(input: &str) -> Result<NaiveDate, Error> {
+    let input_lower = input.trim().to_lowercase();
+    let today = today();
+
+    let date = match input_lower.as_str() {
+        "today" => Some(today),
+        "tomorrow" => today.checked_add_days(Days::new(1)),
+        "yesterday" => today.checked_sub_days(Days::new(1)),
+        other => {
+            if let Some(offset) = other.strip_prefix('+') {
+                parse_offset(offset).and_then(|days| today.checked_add_days(Days::new(days)))
+            } else if let Ok(weekday) = other.parse::<Weekday>() {
+                next_weekday(today, weekday)
+            } else {
+                NaiveDate::parse_from_str(other, "%Y-%m-%d").ok()
+            }
+        }
+    };
+
+    date.ok_or_else(|| errors::invalid_date(input))
+}
+
+// * Parses the start of a date range from the command line
+// ? Accepts the same dates as `parse_date`, or an offset back from today (e.g. `3d`, `2w`)
+pub fn parse_since(input: &str) -> Result<NaiveDate, Error> {
+    match parse_offset(&input.trim().to_lowercase()) {
+        Some(days) => today()
+            .checked_sub_days(Days::new(days))
+            .ok_or_else(|| errors::invalid_date(input)),
+        None => parse_date(input),
+    }
+}
+
+// * Parses a month from the command line and returns its first day
+// ? Accepts `2026-11`, a month name (e.g. `nov`, `november`) or a number from 1 to 12 of this year
+pub fn parse_month(input: &str) -> Result<NaiveDate, Error> {
+    let input_lower = input.trim().to_lowercase();
+    let year = today().year();
+
+    let month = if let Ok(number) = input_lower.parse::<u32>() {
+        NaiveDate::from_ymd_opt(year, number, 1)
+    } else if let Ok(month) = input_lower.parse::<Month>() {
+        NaiveDate::from_ymd_opt(year, month.number_from_month(), 1)
+    } else {
+        NaiveDate::parse_from_str(&format!("{}-01", input_lower), "%Y-%m-%d").ok()
+    };
+
+    month.ok_or_else(|| errors::invalid_month(input))
+}
+
+// * Returns the first day of the current month
+pub fn this_month() -> NaiveDate {
+    let today = today();
+    today - Days::new(today.day0() as u64)
+}
+
+// * Returns the first instant of a day in the local timezone
+pub fn start_of_day(date: NaiveDate) -> DateTime<Local> {
+    let midnight = date.and_hms_opt(0, 0, 0).expect("Midnight is a valid time");
+
+    // NOTE: Midnight can be skipped or repeated around a DST change, so take the earliest match
+    Local
+        .from_local_datetime(&midnight)
+        .earliest()
+        .unwrap_or_else(|| Local.from_utc_datetime(&midnight))
+}
+
+// * Parses a recurrence rule from the command line
+// ? Accepts `daily`, `weekly`, `monthly`, weekdays (e.g. `mon,fri`), an interval (e.g. `3d`, `2w`)
+// ? or an interval after completion (e.g. `after:3d`)
+pub fn parse_recurrence(input: &str) -> Result<Recurrence, Error> {
+    let input_lower = input.trim().to_lowercase();
+
+    let recurrence = match input_lower.as_str() {
+        "daily" | "day" => Some(Recurrence::Daily),
+        "weekly" | "week" => Some(Recurrence::Weekly(Vec::new())),
+        "monthly" | "month" => Some(Recurrence::Monthly),
+        other => {
+            if let Some(offset) = other.strip_prefix("after:") {
+                parse_offset(offset).map(Recurrence::After)
+            } else if let Some(offset) = parse_offset(other) {
+                Some(Recurrence::Every(offset))
+            } else {
+                // TIP: `weekly:` is optional before the weekdays (e.g. `weekly:mon,thu`)
+                let days = other.strip_prefix("weekly:").unwrap_or(other);
+                days.split(',')
+                    .map(|day| day.trim().parse::<Weekday>().ok())
+                    .collect::<Option<Vec<_>>>()
+                    .map(Recurrence::Weekly)
+            }
+        }
+    };
+
+    // Refuse intervals of zero days, which would never move forward,
+    // and intervals so long that the next occurrence would fall past the last supported date
+    match recurrence {
+        Some(Recurrence::Every(0) | Recurrence::After(0)) | None => {
+            Err(errors::invalid_recurrence(input))
+        }
+        Some(Recurrence::Every(days) | Recurrence::After(days))
+            if today().checked_add_days(Days::new(days)).is_none() =>
+        {
+            Err(errors::invalid_recurrence(input))
+        }
+        Some(recurrence) => Ok(recurrence),
+    }
+}
+
+// * Returns the due date of the next occurrence of a recurring todo
+// ? Scheduled rules skip the occurrences already in the past, so an overdue todo catches up
+// ? Fails if the next occurrence would fall past the last supported date
+pub fn next_due(
+    repeat: &Recurrence,
+    due: Option<NaiveDate>,
+    completed: NaiveDate,
+) -> Result<NaiveDate, Error> {
+    let base = due.unwrap_or(completed);
+
+    let next = match repeat {
+        Recurrence::After(days) => completed.checked_add_days(Days::new(*days)),
+
+        // Count the months from the due date, so a todo due on the 31st stays at the end of each month
+        Recurrence::Monthly => (1..)
+            .map_while(|months| base.checked_add_months(Months::new(months)))
+            .find(|&next| next > completed),
+
+        _ => {
+            let mut next = step(repeat, base);
+            while let Some(date) = next
+                && date <= completed
+            {
+                next = step(repeat, date);
+            }
+            next
+        }
+    };
+
+    next.ok_or_else(|| errors::no_next_occurrence(&repeat.describe()))
+}
+
+// * Formats a due date relative to today when it is close (e.g. `today`, `tomorrow`)
+pub fn format_due(due: NaiveDate) -> String {
+    match (due - today()).num_days() {
+        0 => "today".to_string(),
+        1 => "tomorrow".to_string(),
+        -1 => "yesterday".to_string(),
+        _ => due.format("%Y-%m-%d").to_string(),
+    }
+}
+
+// * Returns the bucket a due date falls into, relative to today
+pub fn due_bucket(due: Option<NaiveDate>) -> DueBucket {
+    let Some(due) = due else {
+        return DueBucket::NoDate;
+    };
+
+    let today = today();
+    let end_of_week = today + Days::new(6 - today.weekday().num_days_from_monday() as u64);
+
+    match (due - today).num_days() {
+        ..0 => DueBucket::Overdue,
+        0 => DueBucket::Today,
+        1 => DueBucket::Tomorrow,
+        _ if due <= end_of_week => DueBucket::ThisWeek,
+        _ => DueBucket::Later,
+    }
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Returns the scheduled occurrence following a date, or nothing past the last supported date
+fn step(repeat: &Recurrence, from: NaiveDate) -> Option<NaiveDate> {
+    match repeat {
+        Recurrence::Daily => from.checked_add_days(Days::new(1)),
+        Recurrence::Weekly(days) if days.is_empty() => from.checked_add_days(Days::new(7)),
+        Recurrence::Weekly(days) => days
+            .iter()
+            .filter_map(|&day| next_weekday(from.checked_add_days(Days::new(1))?, day))
+            .min(),
+        Recurrence::Monthly => from.checked_add_months(Months::new(1)),
+        Recurrence::Every(days) | Recurrence::After(days) => {
+            from.checked_add_days(Days::new(*days))
+        }
+    }
+}
+
+// ? Parses an offset in days or weeks (e.g. `3d`, `2w`, or just `3`)
+// ? Gives nothing for too many weeks to count in days
+fn parse_offset(offset: &str) -> Option<u64> {
+    if let Some(weeks) = offset.strip_suffix('w') {
+        return weeks.parse::<u64>().ok()?.checked_mul(7);
+    }
+
+    offset.strip_suffix('d').unwrap_or(offset).parse().ok()
+}
+
+// ? Returns the next date falling on the weekday (today counts as the next one)
+fn next_weekday(from: NaiveDate, weekday: Weekday) -> Option<NaiveDate> {
+    let days_ahead = (7 + weekday.num_days_from_monday() as u64
+        - from.weekday().num_days_from_monday() as u64)
+        % 7;
+
+    from.checked_add_days(Days::new(days_ahead))
+}
@@ -0,0 +1,32 @@
+// * Fuzzy matches a pattern against a text (case-insensitive)
+// ? Returns a score (higher is better) and the positions of the matched characters,
+// ? or `None` if the pattern characters don't all appear in order in the text
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<(i64, Vec<usize>)> {
+    let pattern: Vec<char> = pattern.to_lowercase().chars().collect();
+    let text: Vec<char> = text.chars().collect();
+
+    let mut positions = Vec::with_capacity(pattern.len());
+    let mut score = 0;
+    let mut next = 0;
+
+    for pc in &pattern {
+        let position =
+            (next..text.len()).find(|&i| text[i].to_lowercase().eq(pc.to_lowercase()))?;
+
+        // Reward consecutive matches and matches at the start of a word
+        if positions.last().is_some_and(|&last| last + 1 == position) {
+            score += 5;
+        }
+        if position == 0 || !text[position - 1].is_alphanumeric() {
+            score += 3;
+        }
+
+        // Penalize the gap since the previous match
+        score -= (position - next) as i64;
+
+        positions.push(position);
+        next = position + 1;
+    }
+
+    Some((score, positions))
+}
@@ -6,7 +6,7 @@ use colored::ColoredString;
 pub fn calculate_max_command_length(commands: &[HelpCommand]) -> usize {
     commands
         .iter()
-        .map(|cmd| calculate_components_length(cmd))
+        .map(calculate_components_length)
         .max() // Find the maximum length
         .unwrap_or(0) // Default to 0 if no commands are present
 }
@@ -0,0 +1,102 @@
+use std::io::Error;
+
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+use crate::errors;
+
+// * Named keys of the notation, as written between angle brackets (e.g. `<Up>`)
+const NAMED_KEYS: &[&str] = &[
+    "Up", "Down", "Left", "Right", "Enter", "Esc", "Tab", "Space", "BS", "Del", "Home", "End",
+    "PageUp", "PageDown",
+];
+
+// * Parses a key sequence in vim-like notation into single keys
+// ? e.g. `gg` -> `["g", "g"]`, `<S-Up>` -> `["S-Up"]`, `<C-c>` -> `["C-c"]`
+pub fn parse_keys(notation: &str) -> Result<Vec<String>, Error> {
+    let mut keys = Vec::new();
+    let mut chars = notation.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '<' {
+            keys.push(c.to_string());
+            continue;
+        }
+
+        // Read a named key up to the closing bracket
+        let name: String = chars.by_ref().take_while(|&c| c != '>').collect();
+        let (modifier, key) = match name.split_once('-') {
+            Some((modifier, key)) if !key.is_empty() => (Some(modifier), key),
+            _ => (None, name.as_str()),
+        };
+
+        let valid_key = NAMED_KEYS.contains(&key) || key.chars().count() == 1;
+        let valid_modifier = matches!(modifier, None | Some("S" | "C" | "A"));
+        if !valid_key || !valid_modifier {
+            return Err(errors::invalid_keymap(&format!("Unknown key <{}>", name)));
+        }
+
+        keys.push(name);
+    }
+
+    if keys.is_empty() {
+        return Err(errors::invalid_keymap("Empty key binding"));
+    }
+
+    Ok(keys)
+}
+
+// * Converts a key press into a single key of the notation (e.g. `S-Up`, `C-c`, `G`)
+pub fn key_name(key: KeyEvent) -> Option<String> {
+    let shift = key.modifiers.contains(KeyModifiers::SHIFT);
+    let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+    let alt = key.modifiers.contains(KeyModifiers::ALT);
+
+    let name = match key.code {
+        KeyCode::Char(' ') => "Space".to_string(),
+        KeyCode::Char(c) => c.to_string(), // ? Shift is already part of the character (e.g. `G`)
+        KeyCode::Up => "Up".to_string(),
+        KeyCode::Down => "Down".to_string(),
+        KeyCode::Left => "Left".to_string(),
+        KeyCode::Right => "Right".to_string(),
+        KeyCode::Enter => "Enter".to_string(),
+        KeyCode::Esc => "Esc".to_string(),
+        KeyCode::Tab => "Tab".to_string(),
+        KeyCode::Backspace => "BS".to_string(),
+        KeyCode::Delete => "Del".to_string(),
+        KeyCode::Home => "Home".to_string(),
+        KeyCode::End => "End".to_string(),
+        KeyCode::PageUp => "PageUp".to_string(),
+        KeyCode::PageDown => "PageDown".to_string(),
+        _ => return None,
+    };
+
+    let modifier = if ctrl {
+        "C-"
+    } else if alt {
+        "A-"
+    } else if shift && !matches!(key.code, KeyCode::Char(_)) {
+        "S-"
+    } else {
+        ""
+    };
+
+    Some(format!("{}{}", modifier, name))
+}
+
+// * Formats a key sequence for the help line (e.g. `<S-Up>` -> `⇧↑`, `<Enter>` -> `⏎`)
+pub fn display_keys(notation: &str) -> String {
+    notation
+        .replace("<PageUp>", "PgUp")
+        .replace("<PageDown>", "PgDn")
+        .replace("<S-", "<⇧")
+        .replace("<C-", "<^")
+        .replace("Up>", "↑>")
+        .replace("Down>", "↓>")
+        .replace("Left>", "←>")
+        .replace("Right>", "→>")
+        .replace("<Enter>", "⏎")
+        .replace("<BS>", "⌫")
+        .replace("<Del>", "⌦")
+        .replace("<Space>", "␣")
+        .replace(['<', '>'], "")
+}
@@ -1,4 +1,13 @@
+pub mod args;
+pub mod charts;
+pub mod dates;
+pub mod fuzzy;
 pub mod help;
+pub mod keymap;
+pub mod selectors;
+pub mod stats;
 pub mod terminal;
+pub mod text;
+pub mod theme;
 pub mod timer;
 pub mod todos;
@@ -0,0 +1,128 @@
+use std::io::Error;
+
+use regex::RegexBuilder;
+
+use crate::errors;
+use crate::models::selector::{Selection, Selector};
+use crate::models::todo::Todo;
+use crate::utils::fuzzy::fuzzy_match;
+use crate::utils::terminal;
+use crate::utils::todos::{flatten, parse_numbers, parse_path, validate_index, validate_path};
+use crate::view;
+
+// * Parses a selector argument: a number, a range, a subtask path (e.g. `3.2`) or a `/pattern/` (e.g. `/milk/i`)
+pub fn parse_arg(arg: &str) -> Result<Vec<Selector>, Error> {
+    if let Some(pattern) = arg.strip_prefix('/') {
+        // TIP: A trailing `i` makes the pattern case-insensitive (e.g. `/milk/i`)
+        let (pattern, insensitive) = match pattern.strip_suffix("/i") {
+            Some(pattern) => (pattern, true),
+            None => (pattern.strip_suffix('/').unwrap_or(pattern), false),
+        };
+
+        let regex = RegexBuilder::new(pattern)
+            .case_insensitive(insensitive)
+            .build()
+            .map_err(|e| errors::invalid_pattern(arg, e))?;
+
+        return Ok(vec![Selector::Pattern(regex)]);
+    }
+
+    // TIP: A single dot is a subtask path (`3.2`), two dots are a range (`3..5`)
+    if arg.contains('.') && !arg.contains("..") {
+        return Ok(vec![Selector::Path(parse_path(arg)?)]);
+    }
+
+    let indices = parse_numbers(std::slice::from_ref(&arg.to_string()))?;
+    Ok(indices.into_iter().map(Selector::Index).collect())
+}
+
+// * Resolves a selection into sorted, unique todo paths (e.g. `[3]` for todo 3, `[3, 2]` for `3.2`)
+// ? Text selectors matching several todos are refused, or confirmed in a terminal
+pub fn resolve(selection: &Selection, todos: &[Todo]) -> Result<Vec<Vec<usize>>, Error> {
+    let mut paths = Vec::new();
+
+    for selector in &selection.selectors {
+        match selector {
+            Selector::Index(index) => {
+                validate_index(*index, todos)?;
+                paths.push(vec![*index]);
+            }
+            Selector::Path(path) => {
+                validate_path(path, todos)?;
+                paths.push(path.clone());
+            }
+            _ => paths.extend(resolve_match(selector, todos, selection.all)?),
+        }
+    }
+
+    // TIP: Paths sort parents before their subtasks (e.g. `3`, `3.1`, `3.2`, `4`)
+    paths.sort_unstable();
+    paths.dedup();
+
+    Ok(paths)
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Resolves a text selector, handling the no-match and ambiguous cases
+fn resolve_match(selector: &Selector, todos: &[Todo], all: bool) -> Result<Vec<Vec<usize>>, Error> {
+    let candidates = matching(selector, todos);
+
+    if candidates.is_empty() {
+        return Err(errors::no_match(&selector.describe()));
+    }
+
+    if candidates.len() == 1 || all {
+        return Ok(candidates);
+    }
+
+    view::todos::candidates(&selector.describe(), todos, &candidates);
+
+    // Ask for confirmation if a user is at the terminal, otherwise refuse
+    if !terminal::is_interactive() {
+        return Err(errors::ambiguous_selector(candidates.len()));
+    }
+
+    let question = format!("Use all {} matching todos?", candidates.len());
+    if terminal::confirm(&question)? {
+        Ok(candidates)
+    } else {
+        Err(errors::cancelled())
+    }
+}
+
+// ? Returns the paths of the todos and subtasks matching a text selector
+fn matching(selector: &Selector, todos: &[Todo]) -> Vec<Vec<usize>> {
+    let indexed = flatten(todos).into_iter();
+
+    match selector {
+        Selector::Index(index) => vec![vec![*index]],
+        Selector::Path(path) => vec![path.clone()],
+        Selector::Text(text) => {
+            let text = text.to_lowercase();
+            indexed
+                .filter(|(_, todo)| todo.text.to_lowercase().contains(&text))
+                .map(|(index, _)| index)
+                .collect()
+        }
+        Selector::Pattern(regex) => indexed
+            .filter(|(_, todo)| regex.is_match(&todo.text))
+            .map(|(index, _)| index)
+            .collect(),
+        Selector::Fuzzy(pattern) => {
+            // Keep only the best scoring matches
+            let scored: Vec<(Vec<usize>, i64)> = indexed
+                .filter_map(|(index, todo)| {
+                    fuzzy_match(pattern, &todo.text).map(|(score, _)| (index, score))
+                })
+                .collect();
+
+            let best = scored.iter().map(|(_, score)| *score).max();
+            scored
+                .into_iter()
+                .filter(|(_, score)| Some(*score) == best)
+                .map(|(index, _)| index)
+                .collect()
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Datelike, Days, Local, NaiveDate};
+
+use crate::models::stats::{OldestTodo, Period, Stats};
+use crate::models::todo::Todo;
+use crate::utils::todos::{flatten, format_path};
+
+// * How many days and weeks the activity covers
+pub const DAYS: u64 = 14;
+pub const WEEKS: u64 = 8;
+
+// * Computes the statistics of the todo list at the given time
+// ? Todos created or completed before their timestamps were recorded only count in the totals
+pub fn compute(todos: &[Todo], now: DateTime<Local>) -> Stats {
+    let flat = flatten(todos);
+    let today = now.date_naive();
+
+    let total = flat.len();
+    let done = flat.iter().filter(|(_, todo)| todo.done).count();
+    let open = total - done;
+
+    let created: Vec<NaiveDate> = flat
+        .iter()
+        .filter_map(|(_, todo)| todo.created)
+        .map(|created| created.date_naive())
+        .collect();
+    let completed: Vec<NaiveDate> = flat
+        .iter()
+        .filter(|(_, todo)| todo.done)
+        .filter_map(|(_, todo)| todo.completed)
+        .map(|completed| completed.date_naive())
+        .collect();
+
+    // Count the todos added and completed in each of the last days and weeks
+    let count = |dates: &[NaiveDate], start: NaiveDate, days: u64| {
+        dates
+            .iter()
+            .filter(|&&date| date >= start && date < start + Days::new(days))
+            .count()
+    };
+    let periods = |starts: Vec<NaiveDate>, days: u64| -> Vec<Period> {
+        starts
+            .into_iter()
+            .map(|start| Period {
+                start,
+                added: count(&created, start, days),
+                completed: count(&completed, start, days),
+            })
+            .collect()
+    };
+
+    let monday = today - Days::new(today.weekday().num_days_from_monday() as u64);
+    let days = periods(
+        (0..DAYS)
+            .rev()
+            .map(|back| today - Days::new(back))
+            .collect(),
+        1,
+    );
+    let weeks = periods(
+        (0..WEEKS)
+            .rev()
+            .map(|back| monday - Days::new(back * 7))
+            .collect(),
+        7,
+    );
+
+    // Age of the open todos, in days
+    let open_ages: Vec<(&Vec<usize>, &Todo, f64)> = flat
+        .iter()
+        .filter(|(_, todo)| !todo.done)
+        .filter_map(|(path, todo)| {
+            let created = todo.created?;
+            let age = (now - created).num_minutes() as f64 / (24.0 * 60.0);
+            Some((path, *todo, age))
+        })
+        .collect();
+
+    let average_open_age_days = (!open_ages.is_empty())
+        .then(|| open_ages.iter().map(|(_, _, age)| age).sum::<f64>() / open_ages.len() as f64);
+
+    let oldest_open = open_ages
+        .iter()
+        .filter_map(|(path, todo, _)| todo.created.map(|created| (path, todo, created)))
+        .min_by_key(|(_, _, created)| *created)
+        .map(|(path, todo, created)| OldestTodo {
+            number: format_path(path),
+            text: todo.text.clone(),
+            created: created.date_naive(),
+            age_days: (today - created.date_naive()).num_days(),
+        });
+
+    let (current_streak, longest_streak) = streaks(&completed, today);
+
+    Stats {
+        total,
+        open,
+        done,
+        completion_rate: if total == 0 {
+            0.0
+        } else {
+            done as f64 / total as f64
+        },
+        days,
+        weeks,
+        average_open_age_days,
+        oldest_open,
+        current_streak,
+        longest_streak,
+    }
+}
+
+// * Returns how many todos were open at the end of each day from `start` to `today`
+// ? Todos without a creation date count as always there, done todos without a completion date as never open
+pub fn open_per_day(todos: &[Todo], start: NaiveDate, today: NaiveDate) -> Vec<usize> {
+    let flat = flatten(todos);
+
+    days_between(start, today)
+        .map(|day| {
+            flat.iter()
+                .filter(|(_, todo)| {
+                    let created = todo
+                        .created
+                        .is_none_or(|created| created.date_naive() <= day);
+                    let completed = todo.done
+                        && todo
+                            .completed
+                            .is_none_or(|completed| completed.date_naive() <= day);
+                    created && !completed
+                })
+                .count()
+        })
+        .collect()
+}
+
+// * Returns how many todos were completed on each day from `start` to `today`
+pub fn completed_per_day(todos: &[Todo], start: NaiveDate, today: NaiveDate) -> Vec<usize> {
+    let completed: Vec<NaiveDate> = flatten(todos)
+        .iter()
+        .filter(|(_, todo)| todo.done)
+        .filter_map(|(_, todo)| todo.completed)
+        .map(|completed| completed.date_naive())
+        .collect();
+
+    days_between(start, today)
+        .map(|day| completed.iter().filter(|&&date| date == day).count())
+        .collect()
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Returns the days from `start` to `end`, both included
+fn days_between(start: NaiveDate, end: NaiveDate) -> impl Iterator<Item = NaiveDate> {
+    start.iter_days().take_while(move |&day| day <= end)
+}
+
+// ? Returns the current and the longest runs of consecutive days with a completed todo
+// ? The current run still counts until the end of today, so it can start yesterday
+fn streaks(completed: &[NaiveDate], today: NaiveDate) -> (usize, usize) {
+    let days: BTreeSet<NaiveDate> = completed.iter().copied().collect();
+
+    let mut day = if days.contains(&today) {
+        today
+    } else {
+        today - Days::new(1)
+    };
+    let mut current = 0;
+    while days.contains(&day) {
+        current += 1;
+        day = day - Days::new(1);
+    }
+
+    let mut longest = 0;
+    let mut run = 0;
+    let mut previous: Option<NaiveDate> = None;
+    for &day in &days {
+        run = match previous {
+            Some(previous) if previous + Days::new(1) == day => run + 1,
+            _ => 1,
+        };
+        longest = longest.max(run);
+        previous = Some(day);
+    }
+
+    (current, longest)
+}
@@ -1,11 +1,18 @@
-use std::io::stdout;
+use std::env;
+use std::io::{Error, IsTerminal, Write, stdin, stdout};
+use std::sync::atomic::{AtomicBool, Ordering};
 
 use crossterm::{
     cursor::MoveTo,
     execute,
-    terminal::{Clear, ClearType},
+    terminal::{self, Clear, ClearType},
 };
 
+use crate::data::theme;
+
+// * Whether long rows are cut with `…` instead of wrapped (`--truncate`)
+static TRUNCATE: AtomicBool = AtomicBool::new(false);
+
 // * Clear the terminal screen
 pub fn clear() {
     let mut stdout = stdout();
@@ -16,3 +23,56 @@ pub fn clear() {
     )
     .unwrap();
 }
+
+// * Returns true if a user is at the terminal (both input and output are a TTY)
+pub fn is_interactive() -> bool {
+    stdin().is_terminal() && stdout().is_terminal()
+}
+
+// * Returns true if the output can show Unicode drawing characters (a TTY with a UTF-8 locale)
+// ? Redirected output falls back to ASCII, so it stays readable in logs and files
+pub fn supports_unicode() -> bool {
+    let locale = ["LC_ALL", "LC_CTYPE", "LANG"]
+        .iter()
+        .find_map(|name| env::var(name).ok().filter(|value| !value.is_empty()))
+        .unwrap_or_default()
+        .to_lowercase();
+
+    stdout().is_terminal() && (locale.contains("utf-8") || locale.contains("utf8"))
+}
+
+// * Returns the Unicode symbol, or its ASCII fallback when the output can't show it
+// ? e.g. `symbol("→", "->")` gives `->` in a file
+pub fn symbol(unicode: &'static str, ascii: &'static str) -> &'static str {
+    if supports_unicode() { unicode } else { ascii }
+}
+
+// * Returns the width of the terminal, or nothing when the output is piped
+// ? Piped output is never wrapped, so it stays one line per todo
+pub fn width() -> Option<usize> {
+    if !stdout().is_terminal() {
+        return None;
+    }
+    terminal::size().ok().map(|(width, _)| width as usize)
+}
+
+// * Sets whether long rows are cut with `…` instead of wrapped
+pub fn set_truncate(truncate: bool) {
+    TRUNCATE.store(truncate, Ordering::Relaxed);
+}
+
+// * Returns true if long rows are cut with `…` instead of wrapped
+pub fn truncates() -> bool {
+    TRUNCATE.load(Ordering::Relaxed)
+}
+
+// * Asks a yes/no question and returns true if the answer is yes
+pub fn confirm(question: &str) -> Result<bool, Error> {
+    print!("{} {} ", question, theme::current().hint.paint("[y/N]"));
+    stdout().flush()?;
+
+    let mut answer = String::new();
+    stdin().read_line(&mut answer)?;
+
+    Ok(matches!(answer.trim().to_lowercase().as_str(), "y" | "yes"))
+}
@@ -0,0 +1,243 @@
+use unicode_width::UnicodeWidthChar;
+
+// * Narrowest a wrapped line gets after its indent, below it the row is left as it is
+const MIN_WRAP_WIDTH: usize = 10;
+
+// * Returns the number of terminal columns a text takes, ignoring its colors
+// ? Wide characters (e.g. CJK and most emoji) take two columns
+pub fn width(text: &str) -> usize {
+    tokens(text)
+        .iter()
+        .map(|token| match token {
+            Token::Char(c) => c.width().unwrap_or(0),
+            Token::Escape(_) => 0,
+        })
+        .sum()
+}
+
+// * Wraps a row to a width, indenting the continuation lines so they line up under the text
+// ? Breaks between words when it can, keeps the colors across the lines
+// ? e.g. `12 ☐ a long text` wrapped at 12 with an indent of 5 -> `12 ☐ a long\n     text`
+pub fn wrap(row: &str, width: usize, indent: usize) -> String {
+    if width < indent + MIN_WRAP_WIDTH {
+        return row.to_string();
+    }
+
+    let mut lines: Vec<String> = Vec::new();
+    let mut line = String::new();
+    let mut line_width = 0;
+    let mut active: Vec<String> = Vec::new(); // ? The colors set at this point of the row
+    let mut last_space: Option<(usize, Vec<String>)> = None; // ? Where the line can break
+
+    for token in tokens(row) {
+        let c = match token {
+            Token::Escape(escape) => {
+                if is_reset(&escape) {
+                    active.clear();
+                } else {
+                    active.push(escape.clone());
+                }
+                line.push_str(&escape);
+                continue;
+            }
+            Token::Char(c) => c,
+        };
+
+        let char_width = c.width().unwrap_or(0);
+
+        if line_width + char_width > width {
+            // A space at the end of a line just becomes the break
+            if c == ' ' {
+                lines.push(close(&line, &active));
+                line = open(indent, &active);
+                line_width = indent;
+                last_space = None;
+                continue;
+            }
+
+            // Move the last word to the next line, or cut the word if it is the only one
+            let rest = match last_space.take() {
+                Some((at, colors)) => {
+                    let rest = line.split_off(at);
+                    lines.push(close(&line, &colors));
+                    format!("{}{}", open(indent, &colors), &rest[1..])
+                }
+                None => {
+                    lines.push(close(&line, &active));
+                    open(indent, &active)
+                }
+            };
+            line_width = self::width(&rest);
+            line = rest;
+        }
+
+        if c == ' ' && line_width > indent {
+            last_space = Some((line.len(), active.clone()));
+        }
+        line.push(c);
+        line_width += char_width;
+    }
+
+    lines.push(line);
+    lines.join("\n")
+}
+
+// * Cuts a row to a width, ending with `…` if it was cut
+// ? Wide characters are never split, so the row can end one column short
+pub fn truncate(row: &str, width: usize) -> String {
+    if self::width(row) <= width {
+        return row.to_string();
+    }
+
+    let mut cut = String::new();
+    let mut cut_width = 0;
+    let mut colored = false;
+
+    for token in tokens(row) {
+        match token {
+            Token::Escape(escape) => {
+                colored = true;
+                cut.push_str(&escape);
+            }
+            Token::Char(c) => {
+                let char_width = c.width().unwrap_or(0);
+                if cut_width + char_width > width.saturating_sub(1) {
+                    break;
+                }
+                cut.push(c);
+                cut_width += char_width;
+            }
+        }
+    }
+
+    let mut cut = cut.trim_end_matches(' ').to_string();
+    cut.push('…');
+    if colored {
+        cut.push_str(RESET);
+    }
+    cut
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Resets the colors of the terminal
+const RESET: &str = "\x1b[0m";
+
+// ? A piece of a row: a character shown on screen, or an escape sequence setting its colors
+enum Token {
+    Char(char),
+    Escape(String),
+}
+
+// ? Splits a row into its characters and its color escape sequences (e.g. `\x1b[1;33m`)
+fn tokens(row: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = row.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' && chars.peek() == Some(&'[') {
+            let mut escape = String::from(c);
+            for c in chars.by_ref() {
+                escape.push(c);
+                if c.is_ascii_alphabetic() {
+                    break;
+                }
+            }
+            tokens.push(Token::Escape(escape));
+        } else {
+            tokens.push(Token::Char(c));
+        }
+    }
+
+    tokens
+}
+
+// ? Returns true if the escape sequence resets the colors
+fn is_reset(escape: &str) -> bool {
+    escape == RESET || escape == "\x1b[m"
+}
+
+// ? Ends a line, resetting its colors so they don't spill into the indent of the next one
+fn close(line: &str, active: &[String]) -> String {
+    let line = line.trim_end_matches(' ');
+    if active.is_empty() {
+        line.to_string()
+    } else {
+        format!("{}{}", line, RESET)
+    }
+}
+
+// ? Starts a continuation line with its indent, then the colors still set
+fn open(indent: usize, active: &[String]) -> String {
+    format!("{}{}", " ".repeat(indent), active.concat())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ? Returns the width of each line of a wrapped row
+    fn line_widths(wrapped: &str) -> Vec<usize> {
+        wrapped.lines().map(width).collect()
+    }
+
+    #[test]
+    fn wide_characters_take_two_columns() {
+        assert_eq!(width("todo"), 4);
+        assert_eq!(width("漢字テスト"), 10);
+        assert_eq!(width("ship 🚀"), 7);
+        assert_eq!(width("\x1b[32mdone\x1b[0m"), 4);
+    }
+
+    #[test]
+    fn wrap_breaks_between_words_with_a_hanging_indent() {
+        let wrapped = wrap("1 ☐ buy milk and bread at the store", 16, 4);
+        assert_eq!(wrapped, "1 ☐ buy milk and\n    bread at the\n    store");
+    }
+
+    #[test]
+    fn wrap_never_splits_cjk_characters_across_the_edge() {
+        // ? 11 columns of text after the indent, so a wide character would end half outside
+        let wrapped = wrap("1 ☐ 漢字漢字漢字漢字漢字漢字", 15, 4);
+        assert_eq!(wrapped, "1 ☐ 漢字漢字漢\n    字漢字漢字\n    漢字");
+        assert!(line_widths(&wrapped).iter().all(|&width| width <= 15));
+    }
+
+    #[test]
+    fn wrap_measures_emoji_as_wide() {
+        let wrapped = wrap("1 ☐ 🎉🎉🎉 party 🎉🎉🎉", 14, 4);
+        assert_eq!(wrapped, "1 ☐ 🎉🎉🎉\n    party\n    🎉🎉🎉");
+        assert!(line_widths(&wrapped).iter().all(|&width| width <= 14));
+    }
+
+    #[test]
+    fn wrap_keeps_colors_on_the_continuation_lines() {
+        let wrapped = wrap("1 ☐ \x1b[32mgreen words here\x1b[0m", 16, 4);
+        assert_eq!(
+            wrapped,
+            "1 ☐ \x1b[32mgreen words\x1b[0m\n    \x1b[32mhere\x1b[0m"
+        );
+    }
+
+    #[test]
+    fn wrap_leaves_rows_that_fit_or_narrow_terminals_alone() {
+        assert_eq!(wrap("1 ☐ short", 80, 4), "1 ☐ short");
+        assert_eq!(
+            wrap("1 ☐ too narrow to wrap", 12, 4),
+            "1 ☐ too narrow to wrap"
+        );
+    }
+
+    #[test]
+    fn truncate_ends_with_an_ellipsis_within_the_width() {
+        assert_eq!(truncate("buy milk", 10), "buy milk");
+        assert_eq!(truncate("buy milk and bread", 10), "buy milk…");
+        assert_eq!(truncate("漢字漢字漢字", 6), "漢字…"); // ? The third character would need 2 columns
+        assert_eq!(truncate("party 🎉🎉🎉", 9), "party 🎉…");
+        assert_eq!(width(&truncate("party 🎉🎉🎉", 9)), 9);
+        assert_eq!(
+            truncate("\x1b[31mred text\x1b[0m", 5),
+            "\x1b[31mred…\x1b[0m"
+        );
+    }
+}
@@ -0,0 +1,60 @@
+use std::io::Error;
+
+use colored::Color;
+
+use crate::errors;
+use crate::models::theme::Style;
+
+// * Parses a style from the theme file: a color, a background after `on` and attributes, in any order
+// ? e.g. `green`, `bold bright blue`, `dimmed`, `italic #ff8800`, `white on blue` or `normal` for the terminal color
+pub fn parse_style(input: &str) -> Result<Style, Error> {
+    let mut style = Style::default();
+    let mut words = input.split_whitespace().map(str::to_lowercase);
+
+    while let Some(word) = words.next() {
+        match word.as_str() {
+            "bold" => style.bold = true,
+            "dimmed" | "dim" => style.dimmed = true,
+            "italic" => style.italic = true,
+            "underline" | "underlined" => style.underline = true,
+            "normal" | "default" => style.color = None,
+            // TIP: `bright` goes with the next word (e.g. `bright blue`)
+            "bright" => {
+                let color = words.next().unwrap_or_default();
+                style.color = Some(parse_color(&format!("bright {}", color), input)?);
+            }
+            "on" => {
+                let mut color = words.next().unwrap_or_default();
+                if color == "bright" {
+                    color = format!("bright {}", words.next().unwrap_or_default());
+                }
+                style.background = Some(parse_color(&color, input)?);
+            }
+            color => style.color = Some(parse_color(color, input)?),
+        }
+    }
+
+    Ok(style)
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Parses a color name (e.g. `magenta`, `bright black`) or a hex color (e.g. `#ff8800`)
+fn parse_color(color: &str, style: &str) -> Result<Color, Error> {
+    let invalid = || errors::invalid_theme(&format!("Unknown color in \"{}\"", style));
+
+    if let Some(hex) = color.strip_prefix('#') {
+        // NOTE: Checked as ASCII first, so the byte slices below never split a character
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(|_| invalid());
+        return Ok(Color::TrueColor {
+            r: channel(0)?,
+            g: channel(2)?,
+            b: channel(4)?,
+        });
+    }
+
+    color.parse::<Color>().map_err(|_| invalid())
+}
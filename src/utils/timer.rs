@@ -1,7 +1,17 @@
 use std::time::Duration;
 
+// * Formats a duration with the most readable unit
+// ? From the CLI's own startup time (e.g. `850µs`) to the time worked on a todo (e.g. `1h 05m`)
 pub fn format_duration(duration: Duration) -> String {
-    if duration.as_millis() > 0 {
+    let seconds = duration.as_secs();
+
+    if seconds >= 3600 {
+        format!("{}h {:02}m", seconds / 3600, seconds % 3600 / 60)
+    } else if seconds >= 60 {
+        format!("{}m {:02}s", seconds / 60, seconds % 60)
+    } else if seconds > 0 {
+        format!("{}s", seconds)
+    } else if duration.as_millis() > 0 {
         format!("{}ms", duration.as_millis())
     } else if duration.as_micros() > 0 {
         format!("{}µs", duration.as_micros())
@@ -9,3 +19,12 @@ pub fn format_duration(duration: Duration) -> String {
         format!("{}ns", duration.as_nanos())
     }
 }
+
+// * Formats a number of minutes in hours and minutes (e.g. `45m`, `2h` or `1h 30m`)
+pub fn format_minutes(minutes: u64) -> String {
+    match (minutes / 60, minutes % 60) {
+        (0, minutes) => format!("{}m", minutes),
+        (hours, 0) => format!("{}h", hours),
+        (hours, minutes) => format!("{}h {}m", hours, minutes),
+    }
+}
@@ -1,17 +1,21 @@
+use std::cmp::Reverse;
 use std::io::Error;
 
 use crate::errors;
-use crate::models::todo::Todo;
+use crate::models::estimate::Estimate;
+use crate::models::order::{DueBucket, GroupBy, SortBy};
+use crate::models::todo::{Priority, Todo};
+use crate::utils::dates::{due_bucket, today};
 
-// * Returns a formatted index string with left padding if needed
+// * Returns a formatted index string, padded on the left to line up with the longest one in the list
+// ? e.g. ` 7` in a list of 42 todos, `  7` in a list of 100
 pub fn pad_index(index: usize, length: usize) -> String {
-    let need_padding = length >= 10 && index < 10;
+    format!("{:>width$}", index, width = index_width(length))
+}
 
-    if need_padding {
-        format!(" {}", index) // Index with left padding
-    } else {
-        index.to_string() // No padding needed
-    }
+// * Returns the number of digits of the last index of a list
+pub fn index_width(length: usize) -> usize {
+    length.max(1).to_string().len()
 }
 
 // * Checks if the index is between 1 and the length of the todo list
@@ -27,6 +31,243 @@ pub fn validate_index(index: usize, todos: &[Todo]) -> Result<(), Error> {
     Ok(())
 }
 
+// * Checks if a path (e.g. `[3, 2]` for `3.2`) points to a todo or one of its subtasks
+pub fn validate_path(path: &[usize], todos: &[Todo]) -> Result<(), Error> {
+    let Some((&index, subtasks)) = path.split_first() else {
+        return Err(errors::invalid_number(""));
+    };
+
+    validate_index(index, todos)?;
+
+    // Check each level of subtasks against its parent
+    let mut parent = &todos[index - 1];
+    for (depth, &index) in subtasks.iter().enumerate() {
+        if index == 0 {
+            return Err(errors::invalid_number_zero());
+        }
+
+        if index > parent.children.len() {
+            let parent_path = format_path(&path[..=depth]);
+            return Err(errors::invalid_subtask(
+                &format_path(&path[..depth + 2]),
+                &parent_path,
+                parent.children.len(),
+            ));
+        }
+
+        parent = &parent.children[index - 1];
+    }
+
+    Ok(())
+}
+
+// * Parses a dotted todo path (e.g. `3.2` -> `[3, 2]`, the second subtask of the third todo)
+pub fn parse_path(arg: &str) -> Result<Vec<usize>, Error> {
+    arg.split('.').map(|part| parse_number(part, arg)).collect()
+}
+
+// * Formats a todo path with dots (e.g. `[3, 2]` -> `3.2`)
+pub fn format_path(path: &[usize]) -> String {
+    path.iter()
+        .map(usize::to_string)
+        .collect::<Vec<_>>()
+        .join(".")
+}
+
+// * Returns the todo or subtask at a path, if it exists
+pub fn find<'a>(todos: &'a [Todo], path: &[usize]) -> Option<&'a Todo> {
+    let (&index, subtasks) = path.split_first()?;
+    let todo = todos.get(index.checked_sub(1)?)?;
+
+    if subtasks.is_empty() {
+        Some(todo)
+    } else {
+        find(&todo.children, subtasks)
+    }
+}
+
+// * Returns the todo or subtask at a path to change it, if it exists
+pub fn find_mut<'a>(todos: &'a mut [Todo], path: &[usize]) -> Option<&'a mut Todo> {
+    let (&index, subtasks) = path.split_first()?;
+    let todo = todos.get_mut(index.checked_sub(1)?)?;
+
+    if subtasks.is_empty() {
+        Some(todo)
+    } else {
+        find_mut(&mut todo.children, subtasks)
+    }
+}
+
+// * Returns the list holding the todo at a path (the list itself or the subtasks of its parent)
+pub fn siblings_mut<'a>(todos: &'a mut Vec<Todo>, path: &[usize]) -> Option<&'a mut Vec<Todo>> {
+    match path.split_last()? {
+        (_, []) => Some(todos),
+        (_, parent) => find_mut(todos, parent).map(|parent| &mut parent.children),
+    }
+}
+
+// * Lists every todo and subtask with its path, parents before their subtasks
+pub fn flatten(todos: &[Todo]) -> Vec<(Vec<usize>, &Todo)> {
+    let mut flat = Vec::new();
+
+    for (i, todo) in todos.iter().enumerate() {
+        flat.push((vec![i + 1], todo));
+
+        for (mut path, child) in flatten(&todo.children) {
+            path.insert(0, i + 1);
+            flat.push((path, child));
+        }
+    }
+
+    flat
+}
+
+// * Returns the path of the todo whose timer is running, if any (only one runs at a time)
+pub fn find_running(todos: &[Todo]) -> Option<Vec<usize>> {
+    flatten(todos)
+        .into_iter()
+        .find(|(_, todo)| todo.running().is_some())
+        .map(|(path, _)| path)
+}
+
+// * Gives the todo at a path an id if it has none, and returns it
+pub fn ensure_id(todos: &mut [Todo], path: &[usize]) -> Option<u64> {
+    let next = flatten(todos)
+        .iter()
+        .filter_map(|(_, todo)| todo.id)
+        .max()
+        .unwrap_or(0)
+        + 1;
+
+    let todo = find_mut(todos, path)?;
+    Some(*todo.id.get_or_insert(next))
+}
+
+// * Returns true if a todo waits (directly or through other todos) on the todo with the id `to`
+// ? Used to refuse dependency cycles (e.g. `1` blocked by `2` blocked by `1`)
+pub fn depends_on(todos: &[Todo], from: &Todo, to: u64) -> bool {
+    let flat = flatten(todos);
+    let mut pending = from.blocked_by.clone();
+    let mut seen = Vec::new();
+
+    while let Some(id) = pending.pop() {
+        if id == to {
+            return true;
+        }
+        if seen.contains(&id) {
+            continue;
+        }
+        seen.push(id);
+
+        if let Some((_, todo)) = flat.iter().find(|(_, todo)| todo.id == Some(id)) {
+            pending.extend(&todo.blocked_by);
+        }
+    }
+
+    false
+}
+
+// * Marks the open todos (and subtasks) waiting on a todo that is still open as blocked
+// ? Blockers that were removed from the list don't block anymore
+pub fn mark_blocked(todos: &mut [Todo]) {
+    let open: Vec<u64> = flatten(todos)
+        .iter()
+        .filter(|(_, todo)| !todo.done)
+        .filter_map(|(_, todo)| todo.id)
+        .collect();
+
+    mark_blocked_by(todos, &open);
+}
+
+// * Toggles the done status of the todos at the paths, as `todo done` and the editor do
+// ? A todo done stops its timer, and a recurring one gets its next occurrence at the end of its list
+// ? With `cascade`, the subtasks of each todo get the same status as their parent
+pub fn toggle(
+    todos: &mut Vec<Todo>,
+    paths: &[Vec<usize>],
+    cascade: bool,
+) -> Result<Toggled, Error> {
+    let blocked_before: Vec<Vec<usize>> = blocked_paths(todos);
+
+    let mut occurrences = Vec::new();
+    for path in paths {
+        let todo = find_mut(todos, path).expect("The path was validated");
+        if cascade {
+            todo.set_done_with_subtasks(!todo.done);
+        } else {
+            todo.set_done(!todo.done);
+        }
+
+        // A todo is no longer worked on once it is done
+        if todo.done {
+            todo.stop_timer();
+        }
+
+        // A recurring todo marked as done is kept, and its next occurrence takes over the rule
+        if todo.done
+            && let Some(next) = todo.next_occurrence(today())?
+        {
+            todo.repeat = None;
+            occurrences.push((path.clone(), next));
+        }
+    }
+
+    // Add each next occurrence at the end of its list, so the toggled paths stay valid
+    let mut created = Vec::new();
+    for (path, next) in occurrences {
+        let siblings = siblings_mut(todos, &path).expect("The path was validated");
+        siblings.push(next);
+
+        let mut next_path = path[..path.len() - 1].to_vec();
+        next_path.push(siblings.len());
+        created.push(next_path);
+    }
+
+    // Find the todos that were waiting on the completed ones
+    mark_blocked(todos);
+    let unblocked = flatten(todos)
+        .into_iter()
+        .filter(|(path, todo)| !todo.blocked && blocked_before.contains(path))
+        .map(|(path, _)| path)
+        .collect();
+
+    Ok((created, unblocked))
+}
+
+// * What else changed after toggling todos: the paths of the next occurrences
+// * and of the todos that are no longer blocked
+pub type Toggled = (Vec<Vec<usize>>, Vec<Vec<usize>>);
+
+// * Parses todo numbers, lists and ranges into sorted, unique indices
+// ? e.g. `["1", "3", "5-8", "2..4"]` -> `[1, 2, 3, 4, 5, 6, 7, 8]` (ranges are inclusive)
+pub fn parse_numbers(args: &[String]) -> Result<Vec<usize>, Error> {
+    let mut indices = Vec::new();
+
+    for arg in args {
+        // TIP: Ranges can be written as `5-8` or `5..8`
+        let range = arg.split_once("..").or_else(|| arg.split_once('-'));
+
+        match range {
+            Some((start, end)) => {
+                let start = parse_number(start, arg)?;
+                let end = parse_number(end, arg)?;
+
+                // Refuse ranges longer than any list before expanding them (e.g. `1-99999999999`)
+                if start.abs_diff(end) >= MAX_RANGE {
+                    return Err(errors::invalid_number(arg));
+                }
+                indices.extend(start.min(end)..=start.max(end));
+            }
+            None => indices.push(parse_number(arg, arg)?),
+        }
+    }
+
+    indices.sort_unstable();
+    indices.dedup();
+
+    Ok(indices)
+}
+
 // * Checks if the index is between 1 and the length of the todo list + 1
 // ? This allows adding a new todo at the end of the list
 pub fn validate_index_on_add(index: usize, todos: &[Todo]) -> Result<(), Error> {
@@ -40,3 +281,343 @@ pub fn validate_index_on_add(index: usize, todos: &[Todo]) -> Result<(), Error>
 
     Ok(())
 }
+
+// * Parses an estimate from the command line
+// ? Accepts a time (e.g. `30m`, `2h`, `1h30m`, `1.5h`) or story points (e.g. `3pt`, or just `3`)
+pub fn parse_estimate(input: &str) -> Result<Estimate, Error> {
+    let input_lower = input.trim().to_lowercase().replace(' ', "");
+
+    let points = ["points", "point", "pts", "pt", "p"]
+        .iter()
+        .find_map(|suffix| input_lower.strip_suffix(suffix))
+        .unwrap_or(&input_lower);
+
+    let estimate = match points.parse::<u64>() {
+        Ok(points) => Some(Estimate::Points(points)),
+        Err(_) => parse_minutes(&input_lower).map(Estimate::Minutes),
+    };
+
+    // Refuse empty estimates, which would never count, and estimates too large to mean anything
+    match estimate {
+        Some(Estimate::Minutes(0) | Estimate::Points(0)) | None => {
+            Err(errors::invalid_estimate(input))
+        }
+        Some(Estimate::Minutes(minutes)) if minutes > MAX_ESTIMATE_HOURS * 60 => {
+            Err(errors::invalid_estimate(input))
+        }
+        Some(Estimate::Points(points)) if points > MAX_ESTIMATE_POINTS => {
+            Err(errors::invalid_estimate(input))
+        }
+        Some(estimate) => Ok(estimate),
+    }
+}
+
+// * Parses a priority from the command line (e.g. `high` or `h`)
+pub fn parse_priority(input: &str) -> Result<Priority, Error> {
+    match input.trim().to_lowercase().as_str() {
+        "high" | "h" => Ok(Priority::High),
+        "medium" | "med" | "m" => Ok(Priority::Medium),
+        "low" | "l" => Ok(Priority::Low),
+        _ => Err(errors::invalid_priority(input)),
+    }
+}
+
+// * Parses the `--sort` value of `todo ls`
+pub fn parse_sort(input: &str) -> Result<SortBy, Error> {
+    match input.trim().to_lowercase().as_str() {
+        "created" => Ok(SortBy::Created),
+        "due" => Ok(SortBy::Due),
+        "priority" => Ok(SortBy::Priority),
+        "alpha" => Ok(SortBy::Alpha),
+        "status" => Ok(SortBy::Status),
+        _ => Err(errors::invalid_choice(
+            "sort",
+            input,
+            &["created", "due", "priority", "alpha", "status"],
+        )),
+    }
+}
+
+// * Parses the `--group-by` value of `todo ls`
+pub fn parse_group(input: &str) -> Result<GroupBy, Error> {
+    match input.trim().to_lowercase().as_str() {
+        "status" => Ok(GroupBy::Status),
+        "tag" => Ok(GroupBy::Tag),
+        "due-bucket" | "due" => Ok(GroupBy::DueBucket),
+        "list" => Ok(GroupBy::List),
+        _ => Err(errors::invalid_choice(
+            "group-by",
+            input,
+            &["status", "tag", "due-bucket", "list"],
+        )),
+    }
+}
+
+// * Proposes the open todos that fit in the capacity, ordered by priority and due date
+// ? Only the ready todos estimated in the unit of the capacity count (minutes or points),
+// ? and a todo too big for what is left is skipped so smaller ones can still fit
+pub fn plan(todos: &[Todo], capacity: Estimate) -> Vec<(Vec<usize>, &Todo)> {
+    let mut candidates: Vec<(Vec<usize>, &Todo)> = flatten(todos)
+        .into_iter()
+        .filter(|(_, todo)| !todo.done && !todo.blocked)
+        .filter(|(_, todo)| {
+            todo.estimate
+                .is_some_and(|estimate| same_unit(estimate, capacity))
+        })
+        .collect();
+
+    // TIP: The sort is stable, so equal todos keep the list order
+    candidates.sort_by_key(|(_, todo)| (Reverse(todo.priority), todo.due.is_none(), todo.due));
+
+    let mut left = effort_value(capacity);
+    candidates
+        .into_iter()
+        .filter(|(_, todo)| {
+            let effort = todo.estimate.map(effort_value).unwrap_or_default();
+            let fits = effort <= left;
+            if fits {
+                left -= effort;
+            }
+            fits
+        })
+        .collect()
+}
+
+// * Sorts todos and subtasks (with their paths) for display, keeping the stored order between equal todos
+pub fn sort(todos: &mut [(Vec<usize>, &Todo)], sort_by: SortBy) {
+    match sort_by {
+        // TIP: `Reverse` sorts the highest value first, `None` is always last
+        SortBy::Created => todos.sort_by_key(|(_, todo)| (todo.created.is_none(), todo.created)),
+        SortBy::Due => todos.sort_by_key(|(_, todo)| (todo.due.is_none(), todo.due)),
+        SortBy::Priority => todos.sort_by_key(|(_, todo)| Reverse(todo.priority)),
+        SortBy::Alpha => todos.sort_by_key(|(_, todo)| todo.text.to_lowercase()),
+        SortBy::Status => todos.sort_by_key(|(_, todo)| todo.done),
+    }
+}
+
+// * A titled section of todos and subtasks with their paths (e.g. `#work` and its todos)
+pub type Section<'a> = (String, Vec<(Vec<usize>, &'a Todo)>);
+
+// * Splits todos and subtasks (with their paths) into titled sections
+// ? A todo with several tags shows in each of its tag sections
+pub fn group<'a>(todos: &[(Vec<usize>, &'a Todo)], group_by: GroupBy) -> Vec<Section<'a>> {
+    let section = |keep: &dyn Fn(&Todo) -> bool| -> Vec<(Vec<usize>, &'a Todo)> {
+        todos
+            .iter()
+            .filter(|(_, todo)| keep(todo))
+            .cloned()
+            .collect()
+    };
+
+    let sections = match group_by {
+        GroupBy::Status => vec![
+            ("Open".to_string(), section(&|todo| !todo.done)),
+            ("Done".to_string(), section(&|todo| todo.done)),
+        ],
+        GroupBy::Tag => {
+            let mut tags: Vec<&String> = todos.iter().flat_map(|(_, todo)| &todo.tags).collect();
+            tags.sort();
+            tags.dedup();
+
+            let mut sections: Vec<_> = tags
+                .into_iter()
+                .map(|tag| {
+                    (
+                        format!("#{}", tag),
+                        section(&|todo| todo.tags.contains(tag)),
+                    )
+                })
+                .collect();
+            sections.push((
+                "Untagged".to_string(),
+                section(&|todo| todo.tags.is_empty()),
+            ));
+            sections
+        }
+        GroupBy::DueBucket => [
+            DueBucket::Overdue,
+            DueBucket::Today,
+            DueBucket::Tomorrow,
+            DueBucket::ThisWeek,
+            DueBucket::Later,
+            DueBucket::NoDate,
+        ]
+        .into_iter()
+        .map(|bucket| {
+            let todos = section(&|todo| due_bucket(todo.due) == bucket);
+            (bucket.label().to_string(), todos)
+        })
+        .collect(),
+        GroupBy::List => vec![("Todos".to_string(), todos.to_vec())],
+    };
+
+    // Hide empty sections
+    sections
+        .into_iter()
+        .filter(|(_, todos)| !todos.is_empty())
+        .collect()
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Most todos a range of numbers can cover (e.g. `1-10000`)
+const MAX_RANGE: usize = 10_000;
+
+// ? Largest estimates accepted, in hours and in story points
+const MAX_ESTIMATE_HOURS: u64 = 1000;
+const MAX_ESTIMATE_POINTS: u64 = 1000;
+
+// ? Returns the paths of the todos marked as blocked
+fn blocked_paths(todos: &[Todo]) -> Vec<Vec<usize>> {
+    flatten(todos)
+        .into_iter()
+        .filter(|(_, todo)| todo.blocked)
+        .map(|(path, _)| path)
+        .collect()
+}
+
+// ? Marks the todos waiting on one of the open ids as blocked, then their subtasks
+fn mark_blocked_by(todos: &mut [Todo], open: &[u64]) {
+    for todo in todos {
+        todo.blocked = !todo.done && todo.blocked_by.iter().any(|id| open.contains(id));
+        mark_blocked_by(&mut todo.children, open);
+    }
+}
+
+// ? Parses a single number, reporting the whole argument if it is invalid
+fn parse_number(number: &str, arg: &str) -> Result<usize, Error> {
+    number
+        .trim()
+        .parse::<usize>()
+        .map_err(|_| errors::invalid_number(arg))
+}
+
+// ? Parses a time in hours and minutes into minutes (e.g. `1h30m` -> 90, `1.5h` -> 90, `45m` -> 45)
+fn parse_minutes(input: &str) -> Option<u64> {
+    let (hours, minutes) = match input.split_once('h') {
+        Some((hours, minutes)) => (hours.parse::<f64>().ok()?, minutes),
+        None => (0.0, input),
+    };
+
+    // NOTE: Hours past the largest estimate are refused here, before they lose precision as minutes
+    if !hours.is_finite() || !(0.0..=MAX_ESTIMATE_HOURS as f64).contains(&hours) {
+        return None;
+    }
+
+    let minutes = minutes
+        .strip_suffix("min")
+        .or_else(|| minutes.strip_suffix('m'))
+        .unwrap_or(minutes);
+    let minutes = if minutes.is_empty() {
+        0
+    } else {
+        minutes.parse::<u64>().ok()?
+    };
+
+    ((hours * 60.0).round() as u64).checked_add(minutes)
+}
+
+// ? Returns true if two estimates are in the same unit (both minutes or both points)
+fn same_unit(a: Estimate, b: Estimate) -> bool {
+    matches!(
+        (a, b),
+        (Estimate::Minutes(_), Estimate::Minutes(_)) | (Estimate::Points(_), Estimate::Points(_))
+    )
+}
+
+// ? Returns the amount of an estimate, whatever its unit
+fn effort_value(estimate: Estimate) -> u64 {
+    match estimate {
+        Estimate::Minutes(minutes) => minutes,
+        Estimate::Points(points) => points,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indexes_are_padded_to_the_digits_of_the_list_length() {
+        assert_eq!(pad_index(1, 9), "1");
+        assert_eq!(pad_index(9, 9), "9");
+
+        assert_eq!(pad_index(1, 10), " 1");
+        assert_eq!(pad_index(10, 10), "10");
+
+        assert_eq!(pad_index(1, 99), " 1");
+        assert_eq!(pad_index(99, 99), "99");
+
+        assert_eq!(pad_index(1, 100), "  1");
+        assert_eq!(pad_index(10, 100), " 10");
+        assert_eq!(pad_index(100, 100), "100");
+
+        assert_eq!(pad_index(1, 1000), "   1");
+        assert_eq!(pad_index(99, 1000), "  99");
+        assert_eq!(pad_index(1000, 1000), "1000");
+    }
+
+    #[test]
+    fn index_width_counts_the_digits_of_the_last_index() {
+        assert_eq!(index_width(0), 1);
+        assert_eq!(index_width(9), 1);
+        assert_eq!(index_width(10), 2);
+        assert_eq!(index_width(99), 2);
+        assert_eq!(index_width(100), 3);
+        assert_eq!(index_width(1000), 4);
+    }
+
+    #[test]
+    fn next_occurrence_of_a_cascade_has_new_subtasks() {
+        use crate::models::recurrence::Recurrence;
+
+        let mut step = Todo::new("step");
+        step.id = Some(1);
+        let mut report = Todo::new("weekly report");
+        report.repeat = Some(Recurrence::Weekly(Vec::new()));
+        report.due = Some(today());
+        report.children.push(step);
+        let mut dependent = Todo::new("dependent");
+        dependent.blocked_by = vec![1];
+
+        let mut todos = vec![report, dependent];
+        mark_blocked(&mut todos);
+        assert!(todos[1].blocked);
+
+        let (created, unblocked) = toggle(&mut todos, &[vec![1]], true).unwrap();
+
+        // ? The next occurrence starts open, and its subtask is a new todo without the old id
+        assert_eq!(created, vec![vec![3]]);
+        assert!(!todos[2].done && !todos[2].children[0].done);
+        assert_eq!(todos[2].children[0].id, None);
+        assert_eq!(todos[0].children[0].id, Some(1));
+
+        // ? The dependent waited on the subtask that was done, not on its copy
+        assert_eq!(unblocked, vec![vec![2]]);
+        assert!(!todos[1].blocked);
+    }
+
+    #[test]
+    fn ranges_too_long_for_any_list_are_refused() {
+        let args = |arg: &str| vec![arg.to_string()];
+
+        assert_eq!(parse_numbers(&args("3-1")).unwrap(), vec![1, 2, 3]);
+        assert_eq!(parse_numbers(&args("1-10000")).unwrap().len(), 10_000);
+        assert!(parse_numbers(&args("1-10001")).is_err());
+        assert!(parse_numbers(&args("1-99999999999")).is_err());
+    }
+
+    #[test]
+    fn estimates_past_the_largest_one_are_refused() {
+        assert_eq!(
+            parse_estimate("1000h").ok(),
+            Some(Estimate::Minutes(60_000))
+        );
+        assert_eq!(parse_estimate("1000pt").ok(), Some(Estimate::Points(1000)));
+
+        assert!(parse_estimate("1000h1m").is_err());
+        assert!(parse_estimate("1001pt").is_err());
+        assert!(parse_estimate("999999999999999h").is_err());
+        assert!(parse_estimate("18446744073709551615m").is_err());
+    }
+}
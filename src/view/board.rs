@@ -0,0 +1,128 @@
+use std::io::Error;
+
+use colored::ColoredString;
+use crossterm::terminal;
+
+use crate::data;
+use crate::data::theme;
+use crate::models::board::Board;
+use crate::models::todo::Todo;
+use crate::utils::text;
+use crate::utils::todos::pad_index;
+use crate::view::todos::{divider, empty, title};
+
+// * Spaces between two columns
+const GAP: usize = 2;
+
+// * Narrowest a column gets, so a card still shows its number and a few letters
+const MIN_COLUMN_WIDTH: usize = 8;
+
+// 📢 PUBLIC ----------------------------------
+
+// * Show the todos as cards in side-by-side columns that fit the terminal width (e.g. `todo board`)
+pub fn show() -> Result<(), Error> {
+    let board = data::board::load()?;
+    let todos = data::todos::read()?;
+
+    title();
+
+    if todos.is_empty() {
+        empty();
+        return Ok(());
+    }
+
+    // TIP: Redirected output has no size, so it gets the usual 80 columns
+    let (width, _) = terminal::size().unwrap_or((80, 24));
+    let count = board.columns.len();
+    let column_width =
+        ((width as usize).saturating_sub(GAP * (count - 1)) / count).max(MIN_COLUMN_WIDTH);
+
+    // The cards of each column, keeping their number in the list
+    let cards: Vec<Vec<(usize, &Todo)>> = (0..count)
+        .map(|column| {
+            todos
+                .iter()
+                .enumerate()
+                .filter(|(_, todo)| board.column_of(todo) == column)
+                .map(|(i, todo)| (i + 1, todo))
+                .collect()
+        })
+        .collect();
+
+    // Headers with the number of cards, underlined
+    let headers: Vec<String> = board
+        .columns
+        .iter()
+        .zip(&cards)
+        .enumerate()
+        .map(|(column, (name, cards))| {
+            let header = text::truncate(
+                &format!("{} ({})", name.to_uppercase(), cards.len()),
+                column_width,
+            );
+            let padding = " ".repeat(column_width - text::width(&header));
+            format!("{}{}", header_color(&board, column, &header), padding)
+        })
+        .collect();
+    println!("{}", headers.join(&" ".repeat(GAP)));
+
+    let underline = theme::current()
+        .hint
+        .paint(divider(column_width))
+        .to_string();
+    println!("{}", vec![underline; count].join(&" ".repeat(GAP)));
+
+    // One row of cards at a time, across the columns
+    let rows = cards.iter().map(Vec::len).max().unwrap_or_default();
+    for row in 0..rows {
+        let line: Vec<String> = cards
+            .iter()
+            .enumerate()
+            .map(|(column, cards)| match cards.get(row) {
+                Some((index, todo)) => format_card(
+                    *index,
+                    todos.len(),
+                    todo,
+                    column == board.last(),
+                    column_width,
+                ),
+                None => " ".repeat(column_width),
+            })
+            .collect();
+        println!("{}", line.join(&" ".repeat(GAP)).trim_end());
+    }
+
+    Ok(())
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Colors a column header: a title in the color of open todos for the first column,
+// ? of done todos for the done one, and of the board column of a todo for the others
+fn header_color(board: &Board, column: usize, header: &str) -> ColoredString {
+    let theme = theme::current();
+    let style = if column == 0 {
+        theme.open
+    } else if column == board.last() {
+        theme.done
+    } else {
+        theme.status
+    };
+    style.paint(theme.title.paint(header))
+}
+
+// ? Formats a card with its number, cut and padded to the column width
+// ? Done and blocked cards are dimmed
+fn format_card(index: usize, length: usize, todo: &Todo, done: bool, width: usize) -> String {
+    let number = pad_index(index, length);
+    let text = text::truncate(&todo.text, width.saturating_sub(number.len() + 1));
+    let padding = " ".repeat(width.saturating_sub(number.len() + 1 + text::width(&text)));
+
+    if done || todo.blocked {
+        let card = format!("{} {}{}", number, text, padding);
+        theme::current().hint.paint(card).to_string()
+    } else {
+        let number = theme::current().index.paint(number);
+        format!("{} {}{}", number, text, padding)
+    }
+}
@@ -0,0 +1,156 @@
+use std::io::Error;
+
+use chrono::{Datelike, Days, Months, NaiveDate};
+use colored::Colorize;
+
+use crate::data;
+use crate::data::theme;
+use crate::utils;
+use crate::view::todos::{empty, heading, title};
+
+// * Characters inside a day of the grid (e.g. `[19]  3`)
+const CELL_WIDTH: usize = 7;
+
+// 📢 PUBLIC ----------------------------------
+
+// * Show a month as a grid with the number of open todos due on each day (e.g. `todo cal nov`)
+// ? Subtasks count as todos of their own, today's date is in brackets
+pub fn show(month: NaiveDate) -> Result<(), Error> {
+    let todos = data::todos::read()?;
+
+    title();
+
+    if todos.is_empty() {
+        empty();
+        return Ok(());
+    }
+
+    let next_month = month + Months::new(1);
+    let due: Vec<NaiveDate> = utils::todos::flatten(&todos)
+        .iter()
+        .filter(|(_, todo)| !todo.done)
+        .filter_map(|(_, todo)| todo.due)
+        .filter(|&due| due >= month && due < next_month)
+        .collect();
+    let count = |day: NaiveDate| due.iter().filter(|&&due| due == day).count();
+
+    heading(&month.format("%B %Y").to_string());
+    print_grid(month, next_month, &count);
+
+    // Sum up the month under the grid
+    let today = utils::dates::today();
+    let overdue = due.iter().filter(|&&due| due < today).count();
+    let summary = match (due.len(), overdue) {
+        (0, _) => format!("Nothing due in {}", month.format("%B")),
+        (total, 0) => format!("{} due in {}", total, month.format("%B")),
+        (total, overdue) => format!(
+            "{} due in {} {} {} overdue",
+            total,
+            month.format("%B"),
+            utils::terminal::symbol("·", "-"),
+            overdue
+        ),
+    };
+    println!("{}", theme::current().hint.paint(summary.italic()));
+
+    Ok(())
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Prints the weeks of a month (starting on monday) in a box, in ASCII when the output is piped
+fn print_grid(month: NaiveDate, next_month: NaiveDate, count: &dyn Fn(NaiveDate) -> usize) {
+    let unicode = utils::terminal::supports_unicode();
+    let (vertical, horizontal) = if unicode { ('│', '─') } else { ('|', '-') };
+    let border = |left: char, middle: char, right: char| {
+        let cell = horizontal.to_string().repeat(CELL_WIDTH);
+        format!(
+            "{}{}{}",
+            left,
+            vec![cell; 7].join(&middle.to_string()),
+            right
+        )
+    };
+    let (top, separator, bottom) = if unicode {
+        (
+            border('┌', '┬', '┐'),
+            border('├', '┼', '┤'),
+            border('└', '┴', '┘'),
+        )
+    } else {
+        (
+            border('+', '+', '+'),
+            border('+', '+', '+'),
+            border('+', '+', '+'),
+        )
+    };
+
+    let weekdays: Vec<String> = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"]
+        .iter()
+        .map(|day| format!("{:^width$}", day, width = CELL_WIDTH))
+        .collect();
+    println!(
+        " {}",
+        theme::current().title.paint(weekdays.join(" ").trim_end())
+    );
+    println!("{}", theme::current().hint.paint(top));
+
+    let today = utils::dates::today();
+    let offset = month.weekday().num_days_from_monday() as u64;
+    let mut week_start = month - Days::new(offset);
+
+    while week_start < next_month {
+        let cells: Vec<String> = (0..7)
+            .map(|i| {
+                let day = week_start + Days::new(i);
+                if day < month || day >= next_month {
+                    " ".repeat(CELL_WIDTH)
+                } else {
+                    format_day(day, today, count(day))
+                }
+            })
+            .collect();
+        let vertical = theme::current()
+            .hint
+            .paint(vertical.to_string())
+            .to_string();
+        println!("{}{}{}", vertical, cells.join(&vertical), vertical);
+
+        week_start = week_start + Days::new(7);
+        if week_start < next_month {
+            println!("{}", theme::current().hint.paint(separator.as_str()));
+        }
+    }
+
+    println!("{}", theme::current().hint.paint(bottom));
+}
+
+// ? Formats a day of the grid with its number of todos due (e.g. ` 19   3`)
+// ? Today is in brackets, the counts of past days are red as those todos are overdue
+fn format_day(day: NaiveDate, today: NaiveDate, count: usize) -> String {
+    let number = format!("{:>2}", day.day());
+    let number = if day == today {
+        theme::current()
+            .title
+            .paint(format!("[{}]", number))
+            .to_string()
+    } else if day.weekday().num_days_from_monday() >= 5 {
+        format!(" {} ", theme::current().hint.paint(number))
+    } else {
+        format!(" {} ", number)
+    };
+
+    let count = match count {
+        0 => "  ".to_string(),
+        count if day < today => theme::current()
+            .overdue
+            .paint(theme::current().title.paint(format!("{:>2}", count)))
+            .to_string(),
+        count => theme::current()
+            .due
+            .paint(theme::current().title.paint(format!("{:>2}", count)))
+            .to_string(),
+    };
+
+    format!("{} {}", number, count)
+}
@@ -0,0 +1,169 @@
+use std::io::Error;
+
+use chrono::{Days, NaiveDate};
+use colored::Colorize;
+use crossterm::terminal;
+
+use crate::data;
+use crate::data::theme;
+use crate::models::stats::Chart;
+use crate::utils;
+use crate::view::todos::{empty, heading, title};
+
+// * Rows of the bars of a chart
+const HEIGHT: usize = 8;
+
+// * Widest a bar gets, gap included, when there are few days
+const MAX_BAR_WIDTH: usize = 6;
+
+// 📢 PUBLIC ----------------------------------
+
+// * Show a chart of the completion history since a date, sized to the terminal width
+// ? (e.g. `todo chart burndown --since 2w` or `todo chart activity`)
+pub fn show(chart: Chart, since: NaiveDate) -> Result<(), Error> {
+    let todos = data::todos::read()?;
+
+    title();
+
+    if todos.is_empty() {
+        empty();
+        return Ok(());
+    }
+
+    let today = utils::dates::today();
+    let since = since.min(today);
+    let days = (today - since).num_days() as usize + 1;
+
+    let (label, values) = match chart {
+        Chart::Burndown => ("Burndown", utils::stats::open_per_day(&todos, since, today)),
+        Chart::Activity => (
+            "Activity",
+            utils::stats::completed_per_day(&todos, since, today),
+        ),
+    };
+
+    let (dot, arrow) = (
+        utils::terminal::symbol("·", "-"),
+        utils::terminal::symbol("→", "->"),
+    );
+    heading(&format!("{} {dot} {} days", label, days));
+    print_chart(chart, &values, since, today);
+
+    // Sum up the chart under it
+    println!();
+    let summary = match chart {
+        Chart::Burndown => {
+            let first = values.first().copied().unwrap_or_default();
+            let last = values.last().copied().unwrap_or_default();
+            let change = last as i64 - first as i64;
+            format!(
+                "{} open on {} {arrow} {} open today ({:+})",
+                first,
+                since.format("%b %d"),
+                last,
+                change
+            )
+        }
+        Chart::Activity => {
+            let total: usize = values.iter().sum();
+            let best = values
+                .iter()
+                .enumerate()
+                .max_by_key(|&(i, count)| (*count, i))
+                .filter(|(_, count)| **count > 0)
+                .map(|(i, count)| {
+                    let day = since + Days::new(i as u64);
+                    format!(" {dot} best day {} ({})", day.format("%b %d"), count)
+                })
+                .unwrap_or_default();
+            format!("{} completed{}", total, best)
+        }
+    };
+    println!("{}", theme::current().hint.paint(summary.italic()));
+
+    Ok(())
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Prints the bars with a vertical axis on the left and the dates under them
+fn print_chart(chart: Chart, values: &[usize], since: NaiveDate, today: NaiveDate) {
+    let unicode = utils::terminal::supports_unicode();
+    let (vertical, tick, corner, horizontal) = if unicode {
+        ('│', '┤', '└', '─')
+    } else {
+        ('|', '+', '+', '-')
+    };
+
+    // TIP: Redirected output has no size, so it gets the usual 80 columns
+    let (width, _) = terminal::size().unwrap_or((80, 24));
+
+    let max = values.iter().copied().max().unwrap_or_default();
+    let axis_width = max.to_string().len();
+    let columns = (width as usize).saturating_sub(axis_width + 2).max(1);
+
+    // Several days per column on narrow terminals, wider bars with gaps when there is room
+    let values = utils::charts::fit(values, columns, matches!(chart, Chart::Activity));
+    let slot = (columns / values.len().max(1)).clamp(1, MAX_BAR_WIDTH);
+    let (bar_width, gap) = if slot > 1 {
+        (slot - 1, true)
+    } else {
+        (1, false)
+    };
+
+    let rows = utils::charts::bars(&values, HEIGHT, bar_width, gap, unicode);
+    for (i, row) in rows.iter().enumerate() {
+        let (axis_label, axis) = if i == 0 {
+            (max.to_string(), tick)
+        } else {
+            (String::new(), vertical)
+        };
+        let row = match chart {
+            Chart::Burndown => theme::current().open.paint(row.as_str()),
+            Chart::Activity => theme::current().done.paint(row.as_str()),
+        };
+        println!(
+            "{} {}{}",
+            theme::current()
+                .hint
+                .paint(format!("{:>width$}", axis_label, width = axis_width)),
+            theme::current().hint.paint(axis.to_string()),
+            row
+        );
+    }
+
+    // The horizontal axis, with the first and last dates under the ends of the bars
+    // NOTE: The gap after the last bar isn't part of the chart
+    let chart_width = rows
+        .first()
+        .map(|row| row.chars().count() - gap as usize)
+        .unwrap_or_default();
+    println!(
+        "{}",
+        theme::current().hint.paint(format!(
+            "{:>width$} {}{}",
+            0,
+            corner,
+            horizontal.to_string().repeat(chart_width),
+            width = axis_width
+        ))
+    );
+
+    let start = since.format("%b %d").to_string();
+    let end = today.format("%b %d").to_string();
+    let dates = if chart_width > start.len() + end.len() {
+        format!(
+            "{}{}{}",
+            start,
+            " ".repeat(chart_width - start.len() - end.len()),
+            end
+        )
+    } else {
+        format!("{} {} {}", start, utils::terminal::symbol("→", "->"), end)
+    };
+    println!(
+        "{}{}",
+        " ".repeat(axis_width + 2),
+        theme::current().hint.paint(dates)
+    );
+}
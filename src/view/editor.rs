@@ -0,0 +1,486 @@
+use std::io::{Error, Write};
+
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    terminal::{Clear, ClearType},
+};
+
+use crate::data::theme;
+use crate::models::editor::{Action, Editor};
+use crate::models::keymap::Keymap;
+use crate::utils;
+use crate::utils::keymap::display_keys;
+use crate::utils::todos::pad_index;
+
+// * Rows above the list (blank line, title and divider)
+const HEADER_ROWS: u16 = 3;
+
+// * Rows below the list (help line and status bar)
+const FOOTER_ROWS: u16 = 2;
+
+// * Actions shown in the help line of the editor, with their label
+// ? Each entry shows the first key bound to each of its actions (e.g. `↑/↓ select`)
+const KEY_HELP: &[(&[Action], &str)] = &[
+    (&[Action::Up, Action::Down], "select"),
+    (&[Action::PageUp, Action::PageDown], "page"),
+    (&[Action::Home, Action::End], "jump"),
+    (&[Action::MoveUp, Action::MoveDown], "reorder"),
+    (&[Action::Grab], "grab"),
+    (&[Action::Mark], "mark"),
+    (&[Action::Visual], "range"),
+    (&[Action::Tag], "tag"),
+    (&[Action::Edit], "edit"),
+    (&[Action::Add], "add"),
+    (&[Action::Toggle], "toggle"),
+    (&[Action::ColumnLeft, Action::ColumnRight], "column"),
+    (&[Action::Delete], "delete"),
+    (&[Action::Search], "search"),
+    (&[Action::Undo], "undo"),
+    (&[Action::Quit], "quit"),
+];
+
+// 📢 PUBLIC ----------------------------------
+
+// * Returns how many todos fit on the screen for a terminal of the given height
+pub fn list_rows(height: u16) -> usize {
+    height.saturating_sub(HEADER_ROWS + FOOTER_ROWS).max(1) as usize
+}
+
+// * Draws the whole editor screen for a terminal of the given size
+// ? Only the todos inside the viewport (see `Editor::scroll_into_view`) are drawn,
+// ? and only the matching ones while the search prompt is open
+pub fn render(
+    out: &mut impl Write,
+    editor: &Editor,
+    mode: &str,
+    width: u16,
+    height: u16,
+) -> Result<(), Error> {
+    queue!(out, Clear(ClearType::All))?;
+
+    render_title(out, mode)?;
+
+    let rows = list_rows(height);
+    let targets = if editor.has_selection() {
+        editor.targets()
+    } else {
+        Vec::new()
+    };
+
+    // The todos to show, with the characters matched by the search
+    let matches = editor.matches();
+    let (shown, scroll) = if editor.is_searching() {
+        // The filtered list scrolls on its own, so the selected match stays visible
+        let position = matches
+            .iter()
+            .position(|(i, _)| *i == editor.selected)
+            .unwrap_or(0);
+        (matches, (position + 1).saturating_sub(rows))
+    } else if editor.search.is_some() {
+        let mut matches = matches.into_iter().peekable();
+        let shown = (0..editor.todos.len())
+            .map(|i| match matches.next_if(|(matched, _)| *matched == i) {
+                Some(matched) => matched,
+                None => (i, Vec::new()),
+            })
+            .collect();
+        (shown, editor.scroll)
+    } else {
+        let shown = (0..editor.todos.len()).map(|i| (i, Vec::new())).collect();
+        (shown, editor.scroll)
+    };
+
+    if shown.is_empty() {
+        let message = if editor.todos.is_empty() {
+            "📋 Empty"
+        } else {
+            "🔍 No matches"
+        };
+        queue!(out, MoveTo(0, HEADER_ROWS))?;
+        write!(out, "{}", theme::current().hint.paint(message))?;
+    }
+
+    let visible = shown.iter().skip(scroll).take(rows);
+
+    // Display todos with the selected one highlighted - enforce positioning at column 0
+    for (row, (i, positions)) in visible.enumerate() {
+        let i = *i;
+        let todo = &editor.todos[i];
+        let mut text = highlight_matches(&todo.text, positions);
+
+        // Show the board column of the todos between the first and the done one
+        let column = editor.board.column_of(todo);
+        if column > 0 && column < editor.board.last() {
+            let label = format!("▸ {}", editor.board.columns[column]);
+            text = format!("{} {}", text, theme::current().status.paint(label));
+        }
+
+        // Marked todos (and the visual range) get a marker in front of their number
+        let number = pad_index(i + 1, editor.todos.len());
+        let index_str = if targets.contains(&i) {
+            format!("● {}", number)
+        } else {
+            number
+        };
+        let status = theme::current().status(todo.done);
+
+        queue!(out, MoveTo(0, HEADER_ROWS + row as u16))?; // Position at start of line
+
+        // NOTE: A row that wraps would push the next ones off their positions, so long ones are cut
+        let grabbed = i == editor.selected && editor.grabbed.is_some();
+        let line = format!(
+            "{} {} {}",
+            theme::current().index.paint(index_str),
+            status,
+            text
+        );
+        let line = if grabbed {
+            format!("{} ⇅", line)
+        } else {
+            line
+        };
+        let line = utils::text::truncate(&line, width as usize);
+
+        // Highlight the grabbed todo while it is being moved
+        if grabbed {
+            write!(out, "{}", theme::current().grabbed.paint(line))?;
+        } else if i == editor.selected {
+            write!(out, "{}", theme::current().selected.paint(line))?;
+        } else {
+            write!(out, "{}", line)?;
+        }
+    }
+
+    render_help(out, &editor.keymap, width, height.saturating_sub(2))?;
+    render_status_bar(out, editor, width, height.saturating_sub(1), rows)?;
+
+    out.flush()
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Draws the title and its divider on the first lines
+fn render_title(out: &mut impl Write, mode: &str) -> Result<(), Error> {
+    queue!(out, MoveTo(0, 1))?; // Position at top with one blank line
+    write!(out, "📝 {}", theme::current().title.paint("Todo List "))?;
+    write!(out, "{}", theme::current().status.paint(mode))?;
+    queue!(out, MoveTo(0, 2))?; // Next line, column 0
+    write!(
+        out,
+        "{}",
+        theme::current().hint.paint("──────────────────────")
+    )?;
+    Ok(())
+}
+
+// ? Underlines the characters matched by the search (character positions in the text)
+fn highlight_matches(text: &str, positions: &[usize]) -> String {
+    text.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            if positions.contains(&i) {
+                theme::current().highlight.paint(c.to_string()).to_string()
+            } else {
+                c.to_string()
+            }
+        })
+        .collect()
+}
+
+// ? Draws the key bindings help line, cut to the terminal width so it never wraps
+fn render_help(out: &mut impl Write, keymap: &Keymap, width: u16, row: u16) -> Result<(), Error> {
+    let help: String = KEY_HELP
+        .iter()
+        .filter_map(|(actions, label)| {
+            // Skip the actions left without keys by the user keymap
+            let keys = actions
+                .iter()
+                .map(|action| {
+                    keymap
+                        .keys_for(*action)
+                        .first()
+                        .map(|keys| display_keys(keys))
+                })
+                .collect::<Option<Vec<_>>>()?;
+            Some(format!("{} {}", keys.join("/"), label))
+        })
+        .collect::<Vec<_>>()
+        .join("  ")
+        .chars()
+        .take(width as usize)
+        .collect();
+
+    queue!(out, MoveTo(0, row))?;
+    write!(out, "{}", theme::current().hint.paint(help))?;
+    Ok(())
+}
+
+// ? Draws the status bar on the last line (position, counts, scroll and the last message)
+fn render_status_bar(
+    out: &mut impl Write,
+    editor: &Editor,
+    width: u16,
+    row: u16,
+    rows: usize,
+) -> Result<(), Error> {
+    let done = editor.todos.iter().filter(|todo| todo.done).count();
+    let position = if editor.todos.is_empty() {
+        0
+    } else {
+        editor.selected + 1
+    };
+
+    let mut left = format!(" {}/{} · {} done", position, editor.todos.len(), done);
+
+    // Show how many todos the next batch action applies to
+    if editor.has_selection() {
+        let mode = if editor.visual.is_some() {
+            "VISUAL "
+        } else {
+            ""
+        };
+        left.push_str(&format!(" · {}{} marked", mode, editor.targets().len()));
+    }
+
+    // Show which part of the list is visible when it doesn't fit on the screen
+    let length = editor.todos.len();
+    if length > rows {
+        let last = (editor.scroll + rows).min(length);
+        let above = if editor.scroll > 0 { " ▲" } else { "" };
+        let below = if last < length { " ▼" } else { "" };
+        left.push_str(&format!(
+            " · {}-{} of {}{}{}",
+            editor.scroll + 1,
+            last,
+            length,
+            above,
+            below
+        ));
+    }
+    let right = editor
+        .status
+        .as_deref()
+        .map_or(String::new(), |status| format!("{} ", status));
+
+    // The search prompt replaces the counts while it is open
+    if let Some(search) = editor.search.as_ref().filter(|search| search.typing) {
+        left = format!(
+            " /{}▏ · {} of {} match",
+            search.query,
+            editor.matches().len(),
+            length
+        );
+    }
+
+    // Fill the space between the counts and the message so the bar spans the whole width
+    let used = left.chars().count() + right.chars().count();
+    let fill = " ".repeat((width as usize).saturating_sub(used));
+
+    queue!(out, MoveTo(0, row))?;
+    write!(
+        out,
+        "{}",
+        theme::current()
+            .selected
+            .paint(format!("{}{}{}", left, fill, right))
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::todo::Todo;
+
+    // ? Builds an editor with `count` todos named `item 1`, `item 2`, ...
+    fn editor_with(count: usize, selected: usize) -> Editor {
+        let todos = (1..=count)
+            .map(|i| Todo::new(&format!("item {}", i)))
+            .collect();
+        Editor::new(todos, selected)
+    }
+
+    // ? Renders the editor into a buffer for a fake terminal size
+    fn render_to_string(editor: &mut Editor, width: u16, height: u16) -> String {
+        editor.scroll_into_view(list_rows(height));
+
+        let mut out = Vec::new();
+        render(&mut out, editor, "Edit Mode", width, height).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    // ? Returns the 0-based rows targeted by the cursor moves in the output
+    fn cursor_rows(output: &str) -> Vec<u16> {
+        output
+            .split("\x1b[")
+            .filter_map(|sequence| {
+                let (position, _) = sequence.split_once('H')?;
+                let (row, _) = position.split_once(';')?;
+                row.parse::<u16>().ok().map(|row| row - 1)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn list_rows_leaves_room_for_header_and_footer() {
+        assert_eq!(list_rows(24), 19);
+        assert_eq!(list_rows(6), 1);
+        assert_eq!(list_rows(2), 1);
+    }
+
+    #[test]
+    fn long_rows_are_cut_to_the_width() {
+        let mut editor = editor_with(3, 0);
+        editor.todos[1].text = "漢字".repeat(30);
+
+        // ? `2 ☐ ` takes 4 columns, so 17 wide characters and `…` fit in the other 36
+        let output = render_to_string(&mut editor, 40, 24);
+        assert!(output.contains(&format!("{}漢…", "漢字".repeat(8))));
+        assert!(!output.contains(&"漢字".repeat(9)));
+    }
+
+    #[test]
+    fn numbers_line_up_in_long_lists() {
+        let mut editor = editor_with(100, 0);
+        let output = render_to_string(&mut editor, 80, 200);
+        let plain = regex::Regex::new("\x1b\\[[0-9;]*m")
+            .unwrap()
+            .replace_all(&output, "");
+        // ? Each row ends where the cursor moves to the next one
+        assert!(plain.contains("  1 ☐ item 1\x1b"));
+        assert!(plain.contains(" 10 ☐ item 10\x1b"));
+        assert!(plain.contains("100 ☐ item 100\x1b"));
+    }
+
+    #[test]
+    fn viewport_follows_the_selection() {
+        let mut editor = editor_with(50, 0);
+
+        editor.selected = 30;
+        editor.scroll_into_view(10);
+        assert_eq!(editor.scroll, 21);
+
+        editor.selected = 25;
+        editor.scroll_into_view(10);
+        assert_eq!(editor.scroll, 21); // ? Still visible, no scrolling needed
+
+        editor.selected = 5;
+        editor.scroll_into_view(10);
+        assert_eq!(editor.scroll, 5);
+    }
+
+    #[test]
+    fn viewport_shrinks_with_the_list() {
+        let mut editor = editor_with(50, 49);
+        editor.scroll_into_view(10);
+        assert_eq!(editor.scroll, 40);
+
+        editor.todos.truncate(12);
+        editor.selected = 11;
+        editor.scroll_into_view(10);
+        assert_eq!(editor.scroll, 2);
+    }
+
+    #[test]
+    fn paging_moves_by_visible_rows_and_stops_at_the_edges() {
+        let mut editor = editor_with(30, 0);
+
+        editor.page_down(10);
+        assert_eq!(editor.selected, 10);
+        editor.page_down(100);
+        assert_eq!(editor.selected, 29);
+        editor.page_up(10);
+        assert_eq!(editor.selected, 19);
+        editor.home();
+        assert_eq!(editor.selected, 0);
+        editor.end();
+        assert_eq!(editor.selected, 29);
+    }
+
+    #[test]
+    fn batch_targets_combine_marks_and_visual_range() {
+        let mut editor = editor_with(10, 1);
+        assert_eq!(editor.targets(), vec![1]); // ? Just the selection without marks
+
+        editor.toggle_mark();
+        editor.selected = 5;
+        editor.toggle_visual();
+        editor.selected = 7;
+        assert_eq!(editor.targets(), vec![1, 5, 6, 7]);
+
+        editor.toggle_visual(); // ? Leaving the visual range keeps it marked
+        assert_eq!(editor.visual, None);
+        assert_eq!(editor.targets(), vec![1, 5, 6, 7]);
+    }
+
+    #[test]
+    fn batch_move_keeps_marked_todos_together() {
+        let mut editor = editor_with(5, 3);
+        editor.marked.extend([1, 3]);
+
+        assert!(editor.move_targets(true));
+        let texts: Vec<&str> = editor.todos.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(texts, ["item 2", "item 1", "item 4", "item 3", "item 5"]);
+        assert_eq!(editor.targets(), vec![0, 2]);
+        assert_eq!(editor.selected, 2);
+
+        assert!(!editor.move_targets(true)); // ? The first one is already at the top
+
+        let deleted = editor.delete();
+        assert_eq!(deleted.len(), 2);
+        assert_eq!(editor.todos.len(), 3);
+    }
+
+    #[test]
+    fn search_filters_while_typing_and_jumps_between_matches() {
+        let mut editor = editor_with(12, 0);
+        editor.start_search();
+        editor.set_query("item 1".to_string());
+
+        // ? `item 1`, `item 10`, `item 11` and `item 12` match
+        let output = render_to_string(&mut editor, 80, 24);
+        assert!(output.contains("4 of 12 match"));
+        assert!(!output.contains("item 2"));
+
+        assert!(editor.confirm_search());
+        editor.selected = 9;
+        assert!(editor.next_match(true));
+        assert_eq!(editor.selected, 10);
+        editor.selected = 11;
+        assert!(editor.next_match(true)); // ? Wraps around to the first match
+        assert_eq!(editor.selected, 0);
+        assert!(editor.next_match(false));
+        assert_eq!(editor.selected, 11);
+    }
+
+    #[test]
+    fn render_only_draws_visible_todos() {
+        let mut editor = editor_with(60, 45);
+        let output = render_to_string(&mut editor, 80, 12);
+
+        assert!(output.contains("item 46"));
+        assert!(!output.contains("item 1 "));
+        assert!(!output.contains("item 60"));
+        assert!(output.contains("40-46 of 60 ▲ ▼"));
+    }
+
+    #[test]
+    fn render_stays_inside_the_terminal() {
+        for height in [6, 10, 24] {
+            let mut editor = editor_with(100, 99);
+            let output = render_to_string(&mut editor, 40, height);
+
+            assert!(cursor_rows(&output).iter().all(|&row| row < height));
+            assert!(output.contains("item 100"));
+        }
+    }
+
+    #[test]
+    fn render_hides_scroll_indicator_when_everything_fits() {
+        let mut editor = editor_with(3, 0);
+        let output = render_to_string(&mut editor, 80, 24);
+
+        assert!(!output.contains(" of 3"));
+    }
+}
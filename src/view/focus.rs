@@ -0,0 +1,109 @@
+use std::io::{Error, Write};
+
+use colored::{ColoredString, Colorize};
+use crossterm::{
+    cursor::MoveTo,
+    queue,
+    terminal::{Clear, ClearType},
+};
+
+use crate::data::theme;
+use crate::models::focus::{Focus, Phase};
+
+// * Widest the progress bar gets, in characters
+const BAR_WIDTH: usize = 40;
+
+// * Keys shown at the bottom of the countdown
+const KEY_HELP: &str = "space pause · s skip · q quit";
+
+// 📢 PUBLIC ----------------------------------
+
+// * Draws the pomodoro countdown centered on the screen
+pub fn render(
+    out: &mut impl Write,
+    focus: &Focus,
+    label: &str,
+    width: u16,
+    height: u16,
+) -> Result<(), Error> {
+    queue!(out, Clear(ClearType::All))?;
+
+    let phase = match (focus.phase, focus.paused) {
+        (Phase::Work, false) => theme::current()
+            .high
+            .paint(theme::current().title.paint("🍅 Focus")),
+        (Phase::Break, false) => theme::current()
+            .done
+            .paint(theme::current().title.paint("☕ Break")),
+        (Phase::Work, true) => theme::current().hint.paint("🍅 Focus · paused"),
+        (Phase::Break, true) => theme::current().hint.paint("☕ Break · paused"),
+    };
+
+    // Round the remaining time up, so a 25 minute phase starts at `25:00`
+    let seconds = focus.remaining().as_millis().div_ceil(1000);
+    let clock = format!("{:02}:{:02}", seconds / 60, seconds % 60);
+
+    let bar_width = (width as usize).saturating_sub(4).min(BAR_WIDTH);
+    let progress = focus.elapsed().as_secs_f64() / focus.length().as_secs_f64().max(1.0);
+    let filled = ((bar_width as f64 * progress) as usize).min(bar_width);
+    let bar = format!("{}{}", "█".repeat(filled), "░".repeat(bar_width - filled));
+    let bar = match focus.phase {
+        Phase::Work => theme::current().high.paint(bar),
+        Phase::Break => theme::current().done.paint(bar),
+    };
+
+    let counts = format!("🍅 {}  ·  ☕ {}", focus.pomodoros, focus.breaks);
+
+    let lines = [
+        phase,
+        label.normal(),
+        "".normal(),
+        theme::current().title.paint(clock),
+        bar,
+        "".normal(),
+        theme::current().hint.paint(counts),
+    ];
+
+    // Center the block of lines vertically
+    let top = height.saturating_sub(lines.len() as u16) / 2;
+    for (row, line) in lines.iter().enumerate() {
+        centered(out, line, top + row as u16, width)?;
+    }
+
+    centered(
+        out,
+        &theme::current().hint.paint(KEY_HELP),
+        height.saturating_sub(1),
+        width,
+    )?;
+
+    out.flush()
+}
+
+// * Show how many pomodoros and breaks the session completed
+pub fn summary(focus: &Focus) {
+    println!();
+    println!(
+        "🍅 {} · ☕ {}",
+        plural(focus.pomodoros, "pomodoro"),
+        plural(focus.breaks, "break")
+    );
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Writes a line centered horizontally on the given row
+fn centered(out: &mut impl Write, line: &ColoredString, row: u16, width: u16) -> Result<(), Error> {
+    let length = line.chars().count() as u16;
+    queue!(out, MoveTo(width.saturating_sub(length) / 2, row))?;
+    write!(out, "{}", line)
+}
+
+// ? Formats a count with its noun (e.g. `1 break`, `2 breaks`)
+fn plural(count: u32, noun: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
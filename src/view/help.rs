@@ -1,8 +1,10 @@
 use colored::Colorize;
 
-use crate::data::help::{get_aliases, get_commands};
+use crate::data::commands::GLOBAL_FLAGS;
+use crate::data::help::{get_aliases, get_command_forms, get_commands};
+use crate::models::command::{Command, Flag};
 use crate::models::help::HelpCommand;
-use crate::styles::help::{command, title};
+use crate::styles::help::{command, command_arg, title};
 use crate::utils::help::{
     calculate_components_length, calculate_max_command_length, format_command_components,
 };
@@ -21,17 +23,40 @@ pub fn usage() {
     print_help_info();
 }
 
+// * Show the usage instructions of a single command (e.g. `todo add --help`)
+pub fn command_usage(cmd: &Command) {
+    let forms = get_command_forms(cmd);
+    let max_length = calculate_max_command_length(&forms);
+
+    println!();
+    println!("{}", title("Usage:"));
+    for form in &forms {
+        print_command(form, max_length);
+    }
+    println!();
+
+    if !cmd.aliases.is_empty() {
+        println!("{}", title("Aliases:"));
+        for alias in cmd.aliases {
+            println!("  {}", command(&format!("todo {}", alias)));
+        }
+        println!();
+    }
+
+    print_flags(cmd.flags.iter().chain(GLOBAL_FLAGS));
+}
+
 // 🔒 PRIVATE ---------------------------------
 
 // Print the application introduction
 fn print_app_intro() {
-    println!("");
+    println!();
     println!(
         "Blazing-fast terminal {} list app {}",
         command("todo"),
         "built with Rust".italic()
     );
-    println!("");
+    println!();
 }
 
 // Print quick start example
@@ -40,22 +65,30 @@ fn print_quick_start() {
     println!(
         "  {} {}",
         command("todo add"),
-        "\"your todo text\"".yellow()
+        command_arg("\"your todo text\"")
     );
-    println!("");
+    println!();
 }
 
 // Print where to find more help
 fn print_help_info() {
     println!("To show this help message, run:");
     println!("  {} {}", command("todo"), command("help"),);
+    println!();
+    println!("To show the help of a specific command, run:");
+    println!(
+        "  {} {} {}",
+        command("todo"),
+        command_arg("<command>"),
+        command("--help")
+    );
 }
 
 // Print usage information
 fn print_usage() {
     println!("{}", title("Usage:"));
-    println!("  {} [COMMAND] [TEXT] [ARG]", command("todo"));
-    println!("");
+    println!("  {} [COMMAND] [TEXT] [ARG] [FLAGS]", command("todo"));
+    println!();
 }
 
 // Print commands
@@ -69,7 +102,7 @@ fn print_commands() {
     for cmd in &commands {
         print_command(cmd, max_length);
     }
-    println!("");
+    println!();
 }
 
 // Print a single command with proper formatting
@@ -95,5 +128,39 @@ fn print_aliases() {
     for alias in &aliases {
         print_command(alias, max_length);
     }
-    println!("");
+    println!();
+}
+
+// Print the flags of a command
+fn print_flags<'a>(flags: impl Iterator<Item = &'a Flag> + Clone) {
+    // Format a flag without colors (e.g. `--due, -d <date>`) to calculate the spacing
+    let plain = |flag: &Flag| {
+        let short = flag.short.map_or(String::new(), |s| format!(", -{}", s));
+        let value = flag.value.map_or(String::new(), |v| format!(" {}", v));
+        (format!("--{}{}", flag.long, short), value)
+    };
+
+    let max_length = flags
+        .clone()
+        .map(|flag| {
+            let (name, value) = plain(flag);
+            name.len() + value.len()
+        })
+        .max()
+        .unwrap_or(0);
+
+    println!("{}", title("Flags:"));
+    for flag in flags {
+        let (name, value) = plain(flag);
+        let spaces = " ".repeat(max_length - name.len() - value.len() + 1);
+
+        println!(
+            "  {}{}{} {}",
+            command(&name),
+            command_arg(&value),
+            spaces,
+            flag.description
+        );
+    }
+    println!();
 }
@@ -1,2 +1,9 @@
+pub mod board;
+pub mod calendar;
+pub mod charts;
+pub mod editor;
+pub mod focus;
 pub mod help;
+pub mod report;
+pub mod stats;
 pub mod todos;
@@ -0,0 +1,174 @@
+use std::collections::BTreeMap;
+use std::io::Error;
+use std::time::Duration;
+
+use chrono::{Days, NaiveDate};
+use colored::Colorize;
+
+use crate::data;
+use crate::data::theme;
+use crate::utils;
+use crate::view::todos::{empty, section_title, title};
+
+// 📢 PUBLIC ----------------------------------
+
+// * Show the time worked and the pomodoros per todo and per tag between two dates (both included)
+// ? A todo with several tags counts in each of its tags, a running timer counts until now
+pub fn time(from: Option<NaiveDate>, to: Option<NaiveDate>) -> Result<(), Error> {
+    let todos = data::todos::read()?;
+
+    title();
+
+    if todos.is_empty() {
+        empty();
+        return Ok(());
+    }
+
+    // The range ends at the start of the day after `to`, so that day is included
+    let start = from.map(utils::dates::start_of_day);
+    let end = to
+        .and_then(|to| to.checked_add_days(Days::new(1)))
+        .map(utils::dates::start_of_day);
+
+    // Keep the todos (and subtasks) with time or pomodoros in the range, in list order
+    let items: Vec<_> = utils::todos::flatten(&todos)
+        .into_iter()
+        .map(|(path, todo)| {
+            let spent = todo.time_spent(start, end);
+            let pomodoros = todo.pomodoros_within(start, end);
+            (path, todo, spent, pomodoros)
+        })
+        .filter(|(_, _, spent, pomodoros)| !spent.is_zero() || *pomodoros > 0)
+        .collect();
+
+    if items.is_empty() {
+        println!(
+            "{}",
+            theme::current()
+                .hint
+                .paint("⏱  No time recorded in this range")
+        );
+        return Ok(());
+    }
+
+    // Total the time and the pomodoros of each tag
+    let mut tags: BTreeMap<String, (Duration, usize)> = BTreeMap::new();
+    for (_, todo, spent, pomodoros) in &items {
+        let mut labels: Vec<String> = todo.tags.iter().map(|tag| format!("#{}", tag)).collect();
+        if labels.is_empty() {
+            labels.push("Untagged".to_string());
+        }
+
+        for label in labels {
+            let total = tags.entry(label).or_default();
+            total.0 += *spent;
+            total.1 += pomodoros;
+        }
+    }
+
+    // Most worked tags first
+    let mut tags: Vec<(String, (Duration, usize))> = tags.into_iter().collect();
+    tags.sort_by_key(|(_, (spent, _))| std::cmp::Reverse(*spent));
+
+    // Show the time of each todo, aligned in columns
+    section_title("By todo", items.len());
+    let labels: Vec<String> = items
+        .iter()
+        .map(|(path, todo, _, _)| format!("{} {}", utils::todos::format_path(path), todo.text))
+        .collect();
+    let width = column_width(&labels);
+
+    for ((path, todo, spent, pomodoros), label) in items.iter().zip(&labels) {
+        let number = utils::todos::format_path(path);
+        let running = if todo.running().is_some() { " ⏱" } else { "" };
+        println!(
+            "{} {}{} {}{}{}",
+            theme::current().index.paint(number),
+            todo.text,
+            padding(label, width),
+            theme::current().title.paint(format_spent(*spent)),
+            format_pomodoros(*pomodoros),
+            theme::current().timer.paint(running)
+        );
+    }
+
+    // Show the time of each tag
+    println!();
+    section_title("By tag", tags.len());
+    let labels: Vec<String> = tags.iter().map(|(tag, _)| tag.clone()).collect();
+    let width = column_width(&labels);
+
+    for (tag, (spent, pomodoros)) in &tags {
+        println!(
+            "{}{} {}{}",
+            theme::current().tag.paint(tag.as_str()),
+            padding(tag, width),
+            theme::current().title.paint(format_spent(*spent)),
+            format_pomodoros(*pomodoros)
+        );
+    }
+
+    // Show the total and the range it covers
+    let total: Duration = items.iter().map(|(_, _, spent, _)| *spent).sum();
+    let pomodoros: usize = items.iter().map(|(_, _, _, pomodoros)| pomodoros).sum();
+    let range = match (from, to) {
+        (None, None) => "all time".to_string(),
+        (Some(from), None) => format!("since {}", from.format("%Y-%m-%d")),
+        (None, Some(to)) => format!("until {}", to.format("%Y-%m-%d")),
+        (Some(from), Some(to)) => {
+            format!(
+                "{} {} {}",
+                from.format("%Y-%m-%d"),
+                utils::terminal::symbol("→", "->"),
+                to.format("%Y-%m-%d")
+            )
+        }
+    };
+
+    println!();
+    println!(
+        "{}",
+        theme::current().hint.paint(
+            format!(
+                "{} total{} {} {}",
+                utils::timer::format_duration(total),
+                format_pomodoros(pomodoros),
+                utils::terminal::symbol("·", "-"),
+                range
+            )
+            .italic()
+        )
+    );
+
+    Ok(())
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Returns the width of the widest label, so the durations line up
+fn column_width(labels: &[String]) -> usize {
+    labels
+        .iter()
+        .map(|label| label.chars().count())
+        .max()
+        .unwrap_or_default()
+}
+
+// ? Returns the spaces that fill a label up to the column width
+fn padding(label: &str, width: usize) -> String {
+    " ".repeat(width - label.chars().count())
+}
+
+// ? Formats a pomodoro count after a duration, or nothing if there are none (e.g. ` 🍅3`)
+fn format_pomodoros(pomodoros: usize) -> String {
+    if pomodoros > 0 {
+        format!(" 🍅{}", pomodoros)
+    } else {
+        String::new()
+    }
+}
+
+// ? Formats a duration right-aligned, so the units line up (e.g. ` 25m 00s` under `1h 20m`)
+fn format_spent(spent: Duration) -> String {
+    format!("{:>8}", utils::timer::format_duration(spent))
+}
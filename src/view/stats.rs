@@ -0,0 +1,164 @@
+use std::io::{Error, ErrorKind};
+
+use chrono::Local;
+
+use crate::data;
+use crate::data::theme;
+use crate::models::stats::{Period, Stats};
+use crate::utils;
+use crate::view::todos::{empty, heading, title};
+
+// * Width of the labels of the tables, so the values line up
+const LABEL_WIDTH: usize = 12;
+
+// 📢 PUBLIC ----------------------------------
+
+// * Show the statistics of the todo list, or print them as JSON for dashboards (e.g. `todo stats --json`)
+pub fn show(json: bool) -> Result<(), Error> {
+    let todos = data::todos::read()?;
+    let stats = utils::stats::compute(&todos, Local::now());
+
+    if json {
+        let content = serde_json::to_string_pretty(&stats).map_err(|e| {
+            Error::new(
+                ErrorKind::InvalidData,
+                format!("Could not serialize stats: {}", e),
+            )
+        })?;
+        println!("{}", content);
+        return Ok(());
+    }
+
+    title();
+
+    if todos.is_empty() {
+        empty();
+        return Ok(());
+    }
+
+    print_overview(&stats);
+
+    println!();
+    heading(&format!("Last {} days", utils::stats::DAYS));
+    print_activity(&stats.days);
+
+    println!();
+    heading(&format!("Last {} weeks", utils::stats::WEEKS));
+    print_activity(&stats.weeks);
+
+    Ok(())
+}
+
+// 🔒 PRIVATE ---------------------------------
+
+// ? Prints the counts, the age of the open todos and the streaks as a table
+fn print_overview(stats: &Stats) {
+    heading("Overview");
+    let dot = utils::terminal::symbol("·", "-");
+
+    let rate = format!("{:.0}%", stats.completion_rate * 100.0);
+    print_row(
+        "Open",
+        theme::current()
+            .open
+            .paint(stats.open.to_string())
+            .to_string(),
+    );
+    print_row(
+        "Done",
+        format!(
+            "{} {}",
+            theme::current().done.paint(stats.done.to_string()),
+            theme::current().hint.paint(format!("({})", rate))
+        ),
+    );
+
+    if let Some(age) = stats.average_open_age_days {
+        print_row("Open age", format!("{:.1} days on average", age));
+    }
+
+    if let Some(oldest) = &stats.oldest_open {
+        print_row(
+            "Oldest open",
+            format!(
+                "{} {} {}",
+                theme::current().index.paint(oldest.number.as_str()),
+                oldest.text,
+                theme::current().hint.paint(format!(
+                    "{dot} {}",
+                    plural(oldest.age_days.max(0) as usize, "day")
+                ))
+            ),
+        );
+    }
+
+    print_row(
+        "Streak",
+        format!(
+            "{} {}",
+            theme::current()
+                .title
+                .paint(plural(stats.current_streak, "day")),
+            theme::current()
+                .hint
+                .paint(format!("{dot} longest {}", stats.longest_streak))
+        ),
+    );
+}
+
+// ? Prints the todos added and completed in each period as sparklines, with their totals
+fn print_activity(periods: &[Period]) {
+    let (Some(first), Some(last)) = (periods.first(), periods.last()) else {
+        return;
+    };
+
+    let range = format!(
+        "{} {} {}",
+        first.start.format("%b %d"),
+        utils::terminal::symbol("→", "->"),
+        last.start.format("%b %d")
+    );
+    println!(
+        "{}{}",
+        " ".repeat(LABEL_WIDTH),
+        theme::current().hint.paint(range)
+    );
+
+    let added: Vec<usize> = periods.iter().map(|period| period.added).collect();
+    let completed: Vec<usize> = periods.iter().map(|period| period.completed).collect();
+
+    print_row(
+        "Added",
+        format!(
+            "{} {}",
+            theme::current()
+                .added
+                .paint(utils::charts::sparkline(&added)),
+            added.iter().sum::<usize>()
+        ),
+    );
+    print_row(
+        "Completed",
+        format!(
+            "{} {}",
+            theme::current()
+                .done
+                .paint(utils::charts::sparkline(&completed)),
+            completed.iter().sum::<usize>()
+        ),
+    );
+}
+
+// ? Prints a labelled row of a table (e.g. `Open        8`)
+fn print_row(label: &str, value: String) {
+    println!("{:<width$}{}", label, value, width = LABEL_WIDTH);
+}
+
+// ? Formats a count with its noun (e.g. `1 day`, `3 days`)
+fn plural(count: usize, noun: &str) -> String {
+    if count == 1 {
+        format!("{} {}", count, noun)
+    } else {
+        format!("{} {}s", count, noun)
+    }
+}
@@ -1,9 +1,15 @@
 use std::io::Error;
+use std::time::Duration;
 
 use colored::{ColoredString, Colorize};
+use regex::RegexBuilder;
 
 use crate::data;
-use crate::models::todo::Todo;
+use crate::data::theme;
+use crate::models::estimate::{Effort, Estimate};
+use crate::models::filter::Filter;
+use crate::models::order::{GroupBy, Order, SortBy};
+use crate::models::todo::{Priority, Todo};
 use crate::utils;
 
 // 📢 PUBLIC ----------------------------------
@@ -16,21 +22,23 @@ pub fn title() {
 
     println!(); // Blank line
     println!(
-        "📝 {}  {}",
-        "Todo List".bold(), // Title
-        format!(
+        "{}{}  {}",
+        utils::terminal::symbol("📝 ", ""),
+        theme::current().title.paint("Todo List"), // Title
+        theme::current().hint.paint(format!(
             "{}{}",
-            "⚡".yellow(),
+            theme::current()
+                .timer
+                .paint(utils::terminal::symbol("⚡", "")),
             utils::timer::format_duration(duration) // ⏱️ Show the elapsed time
-        )
-        .dimmed()
+        ))
     );
-    println!("{}", "─────────────────────".dimmed());
+    println!("{}", theme::current().hint.paint(divider(21)));
 }
 
 // * Show Empty message
 pub fn empty() {
-    println!("{}", "📋 Empty".dimmed());
+    println!("{}", theme::current().hint.paint("📋 Empty"));
 }
 
 // * Show all todos in the list
@@ -46,14 +54,21 @@ pub fn all() -> Result<(), Error> {
     for (i, todo) in todos.iter().enumerate() {
         let index = i + 1;
         let (formatted_index, formatted_status) = format_todo(index, todo, length);
-        print_todo(&formatted_index, &formatted_status, &todo.text);
+        print_todo(&formatted_index, &formatted_status, todo);
+        print_subtasks(todo, &[index], &unchanged);
     }
 
+    print_effort(
+        utils::todos::flatten(&todos)
+            .into_iter()
+            .map(|(_, todo)| todo),
+    );
+
     Ok(())
 }
 
-// * Show the newly added todo after adding it to the list
-pub fn added(index: Option<usize>) -> Result<(), Error> {
+// * Show the newly added todo (or subtask) after adding it to the list
+pub fn added(path: &[usize]) -> Result<(), Error> {
     let todos = setup_todos_view()?;
 
     if todos.is_empty() {
@@ -67,40 +82,63 @@ pub fn added(index: Option<usize>) -> Result<(), Error> {
         let todo_index = i + 1;
         let (formatted_index, formatted_status) = format_todo(todo_index, todo, length);
 
-        // Check if the current todo is the newly added one
-        // TIP: `match index` is needed to determine which command was used to add the todo
-        let is_newly_added_todo = match index {
-            Some(idx) => todo_index == idx, // Return true if the provided index is equal to the current index
-
-            // TIP: If an index is provided, it means that the command was `todo add "Todo text" <number>`, so we check if the current index matches the provided index
-            None => i == todos.len() - 1, // Return true if no index is provided and the current index is the last one
-
-                                          // TIP: If no index is provided, it means that the command was `todo add "Todo text"` without a specific index, so the newly added todo is the last one in the list
-        };
-
         // If the current todo is the newly added one, highlight it
-        if is_newly_added_todo {
-            let todo_row = format!("{} + {}", formatted_index, todo.text);
-            println!("{}", todo_row.cyan());
+        if path == [todo_index] {
+            print_row(
+                &added_row(&formatted_index, todo),
+                row_indent(&formatted_index, "+"),
+            );
 
         // Otherwise, print the todo normally
         } else {
             // Print regular todos
-            print_todo(&formatted_index, &formatted_status, &todo.text);
+            print_todo(&formatted_index, &formatted_status, todo);
         }
+
+        // Highlight the newly added subtask (e.g. `todo add "step" --under 3`)
+        print_subtasks(todo, &[todo_index], &|subtask_path, index, subtask| {
+            (subtask_path == path).then(|| added_row(index, subtask))
+        });
     }
 
     Ok(())
 }
 
-// * Show the removed todo after removing it from the list
-pub fn removed(index: usize, removed_todo: &Todo) -> Result<(), Error> {
+// * Show the removed todos after removing them from the list
+// ? Each removed todo is paired with its path in the list before the removal
+pub fn removed(removed_todos: &[(Vec<usize>, Todo)]) -> Result<(), Error> {
+    let todos = setup_todos_view()?;
+
+    if todos.is_empty() {
+        for (_, removed_todo) in removed_todos {
+            // Add padding to the removed todo row if needed
+            let removed_todo_row = format!("-  {}", removed_todo.text.strikethrough());
+            let removed_todo_row = theme::current().removed.paint(removed_todo_row);
+            print_row(&removed_todo_row.to_string(), 3); // show the removed todo
+        }
+        return Ok(());
+    }
+
+    print_with_removed(&todos, removed_todos, &[], &[], todos.len());
+
+    Ok(())
+}
+
+// * Show the toggled todos (done/undone) after toggling their status
+pub fn toggled(paths: &[Vec<usize>]) -> Result<(), Error> {
+    marked(paths)
+}
+
+// * Show the updated todos after changing their metadata (e.g. `todo set 2 --tag work`)
+pub fn updated(paths: &[Vec<usize>]) -> Result<(), Error> {
+    marked(paths)
+}
+
+// * Show the moved todo at its new position, marked with ⇅ and its previous number
+pub fn moved(from: usize, to: usize) -> Result<(), Error> {
     let todos = setup_todos_view()?;
 
     if todos.is_empty() {
-        // Add padding to the removed todo row if needed
-        let removed_todo_row = format!("-  {}", removed_todo.text.strikethrough());
-        println!("{}", removed_todo_row.red()); // show the removed todo
         return Ok(());
     }
 
@@ -111,27 +149,146 @@ pub fn removed(index: usize, removed_todo: &Todo) -> Result<(), Error> {
         let todo_index = i + 1;
         let (formatted_index, formatted_status) = format_todo(todo_index, todo, length);
 
-        // Show removed todo after the todo at its previous position
-        if i == index - 1 {
-            let removed_todo_row = format_removed_todo(removed_todo, length);
-            println!("{}", removed_todo_row.red()); // show the removed todo
+        // If the current todo is the moved one, highlight it
+        if todo_index == to {
+            let moved_todo_row = format!("{} {} {}", formatted_index, formatted_status, todo.text);
+            let marker = theme::current().toggled.paint("⇅");
+            let previous = if from == to {
+                "(not moved)".to_string()
+            } else {
+                format!("(was {})", from)
+            };
+
+            let row = format!(
+                "{}{} {} {}",
+                theme::current().added.paint(moved_todo_row),
+                format_details(todo),
+                marker,
+                theme::current().hint.paint(previous)
+            );
+            print_row(&row, row_indent(&formatted_index, &formatted_status));
+        } else {
+            print_todo(&formatted_index, &formatted_status, todo);
         }
+        print_subtasks(todo, &[todo_index], &unchanged);
+    }
+
+    Ok(())
+}
+
+// * Show a single todo (or subtask) with its subtasks, details and notes
+pub fn show(path: &[usize]) -> Result<(), Error> {
+    let todos = setup_todos_view()?;
+
+    let Some(todo) = utils::todos::find(&todos, path) else {
+        return Ok(());
+    };
+
+    let formatted_path = utils::todos::format_path(path);
+    print_todo(&formatted_path, &format_status(todo), todo);
+    print_subtasks(todo, path, &unchanged);
+    println!();
 
-        // Print the current todo
-        print_todo(&formatted_index, &formatted_status, &todo.text);
+    if let Some(created) = todo.created {
+        let created = format!("Created {}", created.format("%Y-%m-%d %H:%M"));
+        println!("  {}", theme::current().hint.paint(created));
     }
 
-    // If the removed todo was the last one, show it at the end
-    if index == todos.len() + 1 {
-        let removed_todo_row = format_removed_todo(removed_todo, length);
-        println!("{}", removed_todo_row.red()); // show the removed todo at the end
+    // Show the todos it waits on
+    for (blocker_path, blocker) in utils::todos::flatten(&todos) {
+        if blocker.id.is_some_and(|id| todo.blocked_by.contains(&id)) {
+            let status = if blocker.done { "done" } else { "open" };
+            let waiting = format!(
+                "Waits on {} {} ({})",
+                utils::todos::format_path(&blocker_path),
+                blocker.text,
+                status
+            );
+            println!("  {}", theme::current().hint.paint(waiting));
+        }
+    }
+
+    // Show the notes indented under the todo, or how to add some
+    match &todo.notes {
+        Some(notes) => {
+            println!();
+            for line in notes.lines() {
+                println!("  {} {}", theme::current().hint.paint("│"), line);
+            }
+        }
+        None => {
+            let hint = format!("No notes, add some with `todo note {}`", formatted_path);
+            println!("  {}", theme::current().hint.paint(hint));
+        }
     }
 
     Ok(())
 }
 
-// * Show the toggled todo (done/undone) after toggling its status
-pub fn toggled(index: usize) -> Result<(), Error> {
+// * Show the next occurrences created for the completed recurring todos
+pub fn recurred(todos: &[Todo], paths: &[Vec<usize>]) {
+    if paths.is_empty() {
+        return;
+    }
+
+    println!();
+    for path in paths {
+        if let Some(todo) = utils::todos::find(todos, path) {
+            let due = todo.due.map(utils::dates::format_due).unwrap_or_default();
+            println!(
+                "↻ {} {} {} {}",
+                theme::current().added.paint("Next"),
+                theme::current()
+                    .index
+                    .paint(utils::todos::format_path(path)),
+                todo.text,
+                theme::current().hint.paint(format!("📅 {}", due))
+            );
+        }
+    }
+}
+
+// * Show the todo whose timer was stopped and how long it ran
+pub fn stopped(path: &[usize], text: &str, elapsed: Duration) {
+    println!();
+    println!(
+        "⏹  {} {} {} {}",
+        theme::current().toggled.paint("Stopped"),
+        theme::current()
+            .index
+            .paint(utils::todos::format_path(path)),
+        text,
+        theme::current()
+            .hint
+            .paint(format!("after {}", utils::timer::format_duration(elapsed)))
+    );
+}
+
+// * Show the todos that can be started now that their blockers are done
+pub fn unblocked(todos: &[Todo], paths: &[Vec<usize>]) {
+    if paths.is_empty() {
+        return;
+    }
+
+    println!();
+    for path in paths {
+        let Some(todo) = utils::todos::find(todos, path) else {
+            continue;
+        };
+        println!(
+            "🔓 {} {} {}",
+            theme::current().done.paint("Unblocked"),
+            theme::current()
+                .index
+                .paint(utils::todos::format_path(path)),
+            todo.text
+        );
+    }
+}
+
+// * Show the todos and subtasks matching a filter, keeping their original numbers (e.g. `todo ls milk --open`)
+// ? Sorting and grouping only change the display, the numbers still match the stored order
+pub fn list(filter: &Filter, order: &Order) -> Result<(), Error> {
     let todos = setup_todos_view()?;
 
     if todos.is_empty() {
@@ -139,40 +296,166 @@ pub fn toggled(index: usize) -> Result<(), Error> {
     }
 
     let length = todos.len();
+    let flat = utils::todos::flatten(&todos);
 
-    // Show the todos
-    for (i, todo) in todos.iter().enumerate() {
-        let todo_index = i + 1;
-        let (formatted_index, formatted_status) = format_todo(todo_index, todo, length);
+    // Keep the original number (or subtask path) of each matching todo
+    let mut matching: Vec<(Vec<usize>, &Todo)> = flat
+        .iter()
+        .filter(|(_, todo)| filter.matches(todo))
+        .cloned()
+        .collect();
 
-        // Format the todo row
-        let todo_row = format!(
-            "{} {} {}",
-            formatted_index.purple(),
-            formatted_status,
-            todo.text
-        );
-        let toggled_todo_row = format!("{} {} {}", formatted_index, formatted_status, todo.text);
-        let marker = "✦".yellow();
+    if matching.is_empty() {
+        println!("{}", theme::current().hint.paint("🔍 No matching todos"));
+        return Ok(());
+    }
 
-        // If the current todo is the one that was toggled, highlight it
-        if todo_index == index {
-            if todo.done {
-                println!("{} {}", toggled_todo_row.green(), marker); // Done in green
-            } else {
-                println!("{} {}", toggled_todo_row.blue(), marker); // Undone with marker
+    if let Some(sort_by) = order.sort {
+        utils::todos::sort(&mut matching, sort_by);
+    }
+
+    match order.group {
+        Some(group_by) => {
+            for (label, section) in utils::todos::group(&matching, group_by) {
+                section_title(&label, section.len());
+                print_matching(&section, filter, length);
             }
-        // Otherwise, print the todo normally
-        } else {
-            println!("{}", todo_row);
         }
+        None => print_matching(&matching, filter, length),
+    }
+
+    println!(
+        "{}",
+        theme::current()
+            .hint
+            .paint(format!("{} of {} todos", matching.len(), flat.len()).italic())
+    );
+    print_effort(matching.iter().map(|(_, todo)| *todo));
+
+    Ok(())
+}
+
+// * Show the open todos and subtasks with a due date, from the overdue ones to the later ones (e.g. `todo agenda`)
+pub fn agenda() -> Result<(), Error> {
+    let todos = setup_todos_view()?;
+
+    if todos.is_empty() {
+        return Ok(());
+    }
+
+    let length = todos.len();
+    let flat = utils::todos::flatten(&todos);
+
+    let mut due: Vec<(Vec<usize>, &Todo)> = flat
+        .iter()
+        .filter(|(_, todo)| !todo.done && todo.due.is_some())
+        .cloned()
+        .collect();
+
+    if due.is_empty() {
+        println!("{}", theme::current().hint.paint("📅 Nothing due"));
+        return Ok(());
+    }
+
+    utils::todos::sort(&mut due, SortBy::Due);
+    for (label, section) in utils::todos::group(&due, GroupBy::DueBucket) {
+        section_title(&label, section.len());
+        print_matching(&section, &Filter::default(), length);
+    }
+
+    let undated = flat
+        .iter()
+        .filter(|(_, todo)| !todo.done && todo.due.is_none())
+        .count();
+    println!(
+        "{}",
+        theme::current().hint.paint(
+            format!(
+                "{} due {} {} open without a date",
+                due.len(),
+                utils::terminal::symbol("·", "-"),
+                undated
+            )
+            .italic(),
+        )
+    );
+
+    Ok(())
+}
+
+// * Show the open todos that fit in a capacity, in the order to work on them (e.g. `todo plan --capacity 6h`)
+pub fn plan(capacity: Estimate) -> Result<(), Error> {
+    let todos = setup_todos_view()?;
+
+    if todos.is_empty() {
+        return Ok(());
+    }
+
+    let planned = utils::todos::plan(&todos, capacity);
+
+    if planned.is_empty() {
+        let hint = "🗓️  Nothing fits, estimate todos with `todo est <number> 2h`";
+        println!("{}", theme::current().hint.paint(hint));
+        return Ok(());
+    }
+
+    section_title("Plan", planned.len());
+
+    let mut used = Effort::default();
+    for (path, todo) in &planned {
+        let formatted_path = utils::todos::format_path(path);
+        print_todo(&formatted_path, &format_status(todo), todo);
+        used.add(todo.estimate.expect("Planned todos have an estimate"));
+    }
+
+    println!(
+        "{}",
+        theme::current()
+            .hint
+            .paint(format!("{} of {} planned", used.describe(), capacity.describe()).italic())
+    );
+
+    // Remind of the open todos the plan can't account for
+    let unestimated = utils::todos::flatten(&todos)
+        .iter()
+        .filter(|(_, todo)| !todo.done && !todo.blocked && todo.estimate.is_none())
+        .count();
+    if unestimated > 0 {
+        let hint = if unestimated == 1 {
+            "1 open todo has no estimate".to_string()
+        } else {
+            format!("{} open todos have no estimate", unestimated)
+        };
+        println!("{}", theme::current().hint.paint(hint.italic()));
     }
 
     Ok(())
 }
 
-// * Show the replaced todo
-pub fn replaced(index: usize, old_text: &str, new_text: &str) -> Result<(), Error> {
+// * Show a section title with a divider (e.g. `── Open (3) ──────────`)
+pub fn section_title(label: &str, count: usize) {
+    heading(&format!("{} ({})", label, count));
+}
+
+// * Show a heading with a divider (e.g. `── Overview ──────────`)
+pub fn heading(heading: &str) {
+    let divider_length = 21_usize.saturating_sub(heading.chars().count() + 4).max(2);
+
+    println!(
+        "{} {} {}",
+        theme::current().hint.paint(divider(2)),
+        theme::current().title.paint(heading),
+        theme::current().hint.paint(divider(divider_length))
+    );
+}
+
+// * Returns a divider line, in ASCII when the output is piped (see `utils::terminal::supports_unicode`)
+pub fn divider(length: usize) -> String {
+    utils::terminal::symbol("─", "-").repeat(length)
+}
+
+// * Show the replaced todo (or subtask)
+pub fn replaced(path: &[usize], old_text: &str, new_text: &str) -> Result<(), Error> {
     let todos = setup_todos_view()?;
 
     if todos.is_empty() {
@@ -187,25 +470,259 @@ pub fn replaced(index: usize, old_text: &str, new_text: &str) -> Result<(), Erro
         let (formatted_index, formatted_status) = format_todo(todo_index, todo, length);
 
         // If the current todo is the one that was replaced, show old and new text
-        if todo_index == index {
-            // Format and display the old todo
-            let old_todo_row = format!("{} - {}", formatted_index, old_text.strikethrough());
-            println!("{}", old_todo_row.red());
-
-            // Format and display the new todo
-            let new_todo_row = format!("{} + {}", formatted_index, new_text);
-            println!("{}", new_todo_row.cyan());
+        if path == [todo_index] {
+            print_row(
+                &replaced_rows(&formatted_index, old_text, new_text),
+                row_indent(&formatted_index, "+"),
+            );
         } else {
             // Print regular todos
-            print_todo(&formatted_index, &formatted_status, &todo.text);
+            print_todo(&formatted_index, &formatted_status, todo);
         }
+
+        print_subtasks(todo, &[todo_index], &|subtask_path, index, _| {
+            (subtask_path == path).then(|| replaced_rows(index, old_text, new_text))
+        });
     }
 
     Ok(())
 }
 
+// * Show the todos matching an ambiguous selector (e.g. `todo rm /milk/`)
+pub fn candidates(selector: &str, todos: &[Todo], paths: &[Vec<usize>]) {
+    eprintln!(
+        "{} {}",
+        theme::current().title.paint("Multiple todos match"),
+        selector.bold()
+    );
+    for path in paths {
+        if let Some(todo) = utils::todos::find(todos, path) {
+            eprintln!(
+                "{} {} {}",
+                theme::current()
+                    .index
+                    .paint(utils::todos::format_path(path)),
+                format_status(todo),
+                todo.text
+            );
+        }
+    }
+}
+
 // 🔒 PRIVATE ---------------------------------
 
+// ? Shows the list with the given todos highlighted by status and marked with ✦
+fn marked(paths: &[Vec<usize>]) -> Result<(), Error> {
+    let todos = setup_todos_view()?;
+
+    if todos.is_empty() {
+        return Ok(());
+    }
+
+    let length = todos.len();
+
+    // Show the todos
+    for (i, todo) in todos.iter().enumerate() {
+        let todo_index = i + 1;
+        let (formatted_index, formatted_status) = format_todo(todo_index, todo, length);
+
+        // If the current todo is one of the marked ones, highlight it
+        if paths.contains(&vec![todo_index]) {
+            print_row(
+                &marked_row(&formatted_index, todo),
+                row_indent(&formatted_index, &formatted_status),
+            );
+        // Otherwise, print the todo normally
+        } else {
+            print_todo(&formatted_index, &formatted_status, todo);
+        }
+
+        print_subtasks(todo, &[todo_index], &|path, index, subtask| {
+            paths
+                .iter()
+                .any(|marked| marked == path)
+                .then(|| marked_row(index, subtask))
+        });
+    }
+
+    Ok(())
+}
+
+// ? Formats a todo highlighted by status and marked with ✦ (e.g. after `todo done`)
+fn marked_row(index_str: &str, todo: &Todo) -> String {
+    let toggled_todo_row = format!("{} {} {}", index_str, format_status(todo), todo.text);
+    let theme = theme::current();
+    let toggled_todo_row = if todo.done {
+        theme.done.paint(toggled_todo_row) // Done in green
+    } else {
+        theme.open.paint(toggled_todo_row) // Undone in blue
+    };
+
+    format!(
+        "{}{} {}",
+        toggled_todo_row,
+        format_details(todo),
+        theme.toggled.paint("✦")
+    )
+}
+
+// ? Formats a newly added todo
+fn added_row(index_str: &str, todo: &Todo) -> String {
+    theme::current()
+        .added
+        .paint(format!("{} + {}", index_str, todo.text))
+        .to_string()
+}
+
+// ? Formats the old and new text of a replaced todo, on two lines
+fn replaced_rows(index_str: &str, old_text: &str, new_text: &str) -> String {
+    let old_todo_row = format!("{} - {}", index_str, old_text.strikethrough());
+    let new_todo_row = format!("{} + {}", index_str, new_text);
+
+    let theme = theme::current();
+    format!(
+        "{}\n{}",
+        theme.removed.paint(old_todo_row),
+        theme.added.paint(new_todo_row)
+    )
+}
+
+// ? Prints the subtasks of a todo indented under it, numbered with their path (e.g. `3.2`)
+// ? `changed` can return the row to print instead of the standard one (e.g. for a toggled subtask)
+fn print_subtasks<F>(todo: &Todo, path: &[usize], changed: &F)
+where
+    F: Fn(&[usize], &str, &Todo) -> Option<String>,
+{
+    for (i, subtask) in todo.children.iter().enumerate() {
+        let subtask_path = [path, &[i + 1]].concat();
+        let index_str = subtask_index(&subtask_path);
+
+        match changed(&subtask_path, &index_str, subtask) {
+            Some(row) => print_row(&row, row_indent(&index_str, &format_status(subtask))),
+            None => print_todo(&index_str, &format_status(subtask), subtask),
+        }
+
+        print_subtasks(subtask, &subtask_path, changed);
+    }
+}
+
+// ? Used with `print_subtasks` when no subtask has changed
+fn unchanged(_: &[usize], _: &str, _: &Todo) -> Option<String> {
+    None
+}
+
+// ? Formats the number of a subtask, indented by its depth (e.g. `  3.2`)
+fn subtask_index(path: &[usize]) -> String {
+    format!(
+        "{}{}",
+        "  ".repeat(path.len().saturating_sub(1)),
+        utils::todos::format_path(path)
+    )
+}
+
+// ? Prints a level of the list as it was before a removal, with the removed todos at their old place
+// ? `old_prefix` and `new_prefix` are the paths of the parent before and after the removal
+fn print_with_removed(
+    remaining: &[Todo],
+    removed_todos: &[(Vec<usize>, Todo)],
+    old_prefix: &[usize],
+    new_prefix: &[usize],
+    length: usize,
+) {
+    let removed_here = removed_todos
+        .iter()
+        .filter(|(path, _)| path.len() == old_prefix.len() + 1 && path.starts_with(old_prefix))
+        .count();
+    let mut remaining = remaining.iter().enumerate();
+
+    // Walk the level as it was before the removal, so each removed todo shows at its previous position
+    for position in 1..=remaining.len() + removed_here {
+        let old_path = [old_prefix, &[position]].concat();
+
+        match removed_todos.iter().find(|(path, _)| *path == old_path) {
+            Some((_, removed_todo)) => {
+                let indent = "  ".repeat(old_prefix.len());
+                let removed_todo_row = format_removed_todo(removed_todo, length);
+                // The text starts after the marker and the status (e.g. ` - ☐ `)
+                let text_start = indent.len() + utils::text::width(&removed_todo_row)
+                    - utils::text::width(&removed_todo.text);
+                let row = format!(
+                    "{}{}",
+                    indent,
+                    theme::current().removed.paint(removed_todo_row)
+                );
+                print_row(&row, text_start); // show the removed todo
+            }
+            None => {
+                if let Some((i, todo)) = remaining.next() {
+                    let new_path = [new_prefix, &[i + 1]].concat();
+                    let formatted_index = if new_prefix.is_empty() {
+                        utils::todos::pad_index(i + 1, length)
+                    } else {
+                        subtask_index(&new_path)
+                    };
+                    print_todo(&formatted_index, &format_status(todo), todo);
+                    print_with_removed(&todo.children, removed_todos, &old_path, &new_path, length);
+                }
+            }
+        }
+    }
+}
+
+// ? Helper function to print todos and subtasks by path with the search terms highlighted
+// ? Top-level numbers are padded to line up, subtasks show their full path (e.g. `3.2`)
+fn print_matching(todos: &[(Vec<usize>, &Todo)], filter: &Filter, list_length: usize) {
+    for (path, todo) in todos {
+        let (formatted_index, formatted_status) = match path.as_slice() {
+            [index] => format_todo(*index, todo, list_length),
+            _ => (utils::todos::format_path(path), format_status(todo)),
+        };
+
+        // Blocked todos show without search highlighting, see `print_todo`
+        if todo.blocked {
+            print_todo(&formatted_index, &formatted_status, todo);
+            continue;
+        }
+
+        let row = format!(
+            "{} {} {}{}",
+            theme::current().index.paint(formatted_index.as_str()),
+            formatted_status,
+            highlight(&todo.text, &filter.terms),
+            format_details(todo)
+        );
+        print_row(&row, row_indent(&formatted_index, &formatted_status));
+    }
+}
+
+// ? Prints the effort left and done on the todos, if any of them has an estimate
+fn print_effort<'a>(todos: impl Iterator<Item = &'a Todo>) {
+    let mut left = Effort::default();
+    let mut done = Effort::default();
+
+    for todo in todos {
+        if let Some(estimate) = todo.estimate {
+            if todo.done {
+                done.add(estimate);
+            } else {
+                left.add(estimate);
+            }
+        }
+    }
+
+    if left.is_zero() && done.is_zero() {
+        return;
+    }
+
+    let effort = format!(
+        "Effort: {} left {} {} done",
+        left.describe(),
+        utils::terminal::symbol("·", "-"),
+        done.describe()
+    );
+    println!("{}", theme::current().hint.paint(effort.italic()));
+}
+
 // ? Helper function to setup todos view and handle empty list case
 fn setup_todos_view() -> Result<Vec<Todo>, Error> {
     let todos = data::todos::read()?;
@@ -224,26 +741,179 @@ fn format_todo(index: usize, todo: &Todo, list_length: usize) -> (String, Colore
     // Format index with padding if needed
     let formatted_index = utils::todos::pad_index(index, list_length);
 
-    // Format status with color
-    let status = if todo.done { "✔︎" } else { "☐" };
-    let formatted_status = if todo.done {
-        status.green()
+    (formatted_index, format_status(todo))
+}
+
+// ? Helper function to format a todo's status with the marks and colors of the theme
+fn format_status(todo: &Todo) -> ColoredString {
+    theme::current().status(todo.done)
+}
+
+// ? Helper function to print a standard todo item
+// ? Blocked todos are dimmed with a lock, as they can't be started yet
+fn print_todo(index_str: &str, status: &ColoredString, todo: &Todo) {
+    let row = if todo.blocked {
+        let row = format!("{} {} {}", index_str, status.clone().clear(), todo.text);
+        format!(
+            "{}{} 🔒",
+            theme::current().hint.paint(row),
+            format_details(todo)
+        )
     } else {
-        status.blue()
+        format!(
+            "{} {} {}{}",
+            theme::current().index.paint(index_str),
+            status,
+            todo.text,
+            format_details(todo)
+        )
     };
 
-    (formatted_index, formatted_status)
+    print_row(&row, row_indent(index_str, status));
 }
 
-// ? Helper function to print a standard todo item
-fn print_todo(index_str: &str, status: &ColoredString, text: &str) {
-    println!("{} {} {}", index_str.purple(), status, text);
+// ? Prints a row (or a few) fitted to the terminal width: wrapped under the text, or cut with `…`
+// ? with `--truncate`. `indent` is the width of the number and status before the text
+fn print_row(row: &str, indent: usize) {
+    let Some(width) = utils::terminal::width() else {
+        println!("{}", row);
+        return;
+    };
+
+    for line in row.lines() {
+        if utils::terminal::truncates() {
+            println!("{}", utils::text::truncate(line, width));
+        } else {
+            println!("{}", utils::text::wrap(line, width, indent));
+        }
+    }
+}
+
+// ? Returns the width before the text of a row with this number and mark (e.g. `12 ☐ ` -> 5)
+fn row_indent(index_str: &str, mark: &str) -> usize {
+    utils::text::width(index_str) + utils::text::width(mark) + 2
+}
+
+// ? Helper function to format the metadata shown after a todo's text (priority, due date, tags)
+fn format_details(todo: &Todo) -> String {
+    let mut details = String::new();
+
+    // Show the board column of the todos in progress (see `todo board`)
+    if let Some(status) = &todo.status {
+        let status = theme::current().status.paint(format!("▸ {}", status));
+        details.push_str(&format!(" {}", status));
+    }
+
+    // Show how many subtasks are done (e.g. `2/5`)
+    if let Some((done, total)) = todo.progress() {
+        let progress = format!("{}/{}", done, total);
+        let progress = if done == total {
+            theme::current().done.paint(progress)
+        } else {
+            theme::current().hint.paint(progress)
+        };
+        details.push_str(&format!(" {}", progress));
+    }
+
+    if let Some(priority) = todo.priority {
+        let marker = match priority {
+            Priority::High => theme::current().high.paint("!!!"),
+            Priority::Medium => theme::current().medium.paint("!!"),
+            Priority::Low => theme::current().low.paint("!"),
+        };
+        details.push_str(&format!(" {}", marker));
+    }
+
+    if let Some(due) = todo.due {
+        let formatted_due = format!("📅 {}", utils::dates::format_due(due));
+        let overdue = !todo.done && due < utils::dates::today();
+        let formatted_due = if overdue {
+            theme::current().overdue.paint(formatted_due)
+        } else {
+            theme::current().hint.paint(formatted_due)
+        };
+        details.push_str(&format!(" {}", formatted_due));
+    }
+
+    if let Some(estimate) = todo.estimate {
+        details.push_str(&format!(
+            " {}",
+            theme::current()
+                .hint
+                .paint(format!("~{}", estimate.describe()))
+        ));
+    }
+
+    for tag in &todo.tags {
+        let tag = theme::current().tag.paint(format!("#{}", tag));
+        details.push_str(&format!(" {}", tag));
+    }
+
+    // Show how long the running timer has been going (see `todo start`)
+    if let Some(interval) = todo.running() {
+        let elapsed = utils::timer::format_duration(interval.within(None, None));
+        let timer = theme::current().timer.paint(format!("⏱ {}", elapsed));
+        details.push_str(&format!(" {}", timer));
+    }
+
+    // Show how many pomodoros were completed (see `todo focus`)
+    if !todo.pomodoros.is_empty() {
+        details.push_str(&format!(" 🍅{}", todo.pomodoros.len()));
+    }
+
+    if let Some(repeat) = &todo.repeat {
+        let repeat = theme::current()
+            .hint
+            .paint(format!("↻ {}", repeat.describe()));
+        details.push_str(&format!(" {}", repeat));
+    }
+
+    // Show that the todo has notes (see `todo show`)
+    if todo.notes.is_some() {
+        details.push_str(" 📎");
+    }
+
+    details
+}
+
+// ? Helper function to highlight the search terms found in a text (case-insensitive)
+fn highlight(text: &str, terms: &[String]) -> String {
+    if terms.is_empty() {
+        return text.to_string();
+    }
+
+    let pattern = terms
+        .iter()
+        .map(|term| regex::escape(term))
+        .collect::<Vec<_>>()
+        .join("|");
+    let regex = RegexBuilder::new(&pattern)
+        .case_insensitive(true)
+        .build()
+        .expect("Escaped terms are always a valid pattern");
+
+    let mut highlighted = String::new();
+    let mut last = 0;
+    for found in regex.find_iter(text) {
+        highlighted.push_str(&text[last..found.start()]);
+        highlighted.push_str(&theme::current().highlight.paint(found.as_str()).to_string());
+        last = found.end();
+    }
+    highlighted.push_str(&text[last..]);
+
+    highlighted
 }
 
 // ? Helper function to format a removed todo
 fn format_removed_todo(todo: &Todo, list_length: usize) -> String {
-    let removed_status = if todo.done { "✔︎" } else { "☐" };
-    let padding = if list_length >= 10 { " " } else { "" };
+    let theme = theme::current();
+    let removed_status = if todo.done {
+        &theme.done_mark
+    } else {
+        &theme.open_mark
+    };
+    // The `-` takes the place of the last digit of the number
+    let padding = " ".repeat(utils::todos::index_width(list_length) - 1);
 
     format!(
         "{}- {} {}",